@@ -1,4 +1,3 @@
-
 import { GearApi, decodeAddress } from '@gear-js/api';
 import { TypeRegistry } from '@polkadot/types';
 import { TransactionBuilder, getServiceNamePrefix, getFnNamePrefix, ZERO_ADDRESS } from 'sails-js';
@@ -8,34 +7,260 @@ export type ActorId = string;
 export interface InitRegistrar {
   registry: ActorId;
   tld_node: bigint | string;
+  hash_scheme: HashScheme;
   controller: ActorId;
-  base_price: bigint | string;
-  premium_price: bigint | string;
+  price_tiers: PriceTiers;
+  min_commit_age: bigint | string;
+  max_commit_age: bigint | string;
+  grace_period: bigint | string;
+}
+
+export type HashScheme = 'Blake2' | 'Keccak';
+
+export interface PriceTiers {
+  per_year: [bigint | string, bigint | string, bigint | string, bigint | string, bigint | string];
+  multi_year_discount: number;
+}
+
+export interface ExpiryPremium {
+  start: bigint | string;
+  half_life: bigint | string;
+  window: bigint | string;
+}
+
+export type RegistrarError =
+  | { notController: null }
+  | { nameTooLong: null }
+  | { nameReserved: null }
+  | { nameNotAvailable: null }
+  | { nameNotRenewable: null }
+  | { addrRequiresResolver: null }
+  | { commitmentAlreadyExists: null }
+  | { tooManyCommitments: null }
+  | { noValidCommitment: null }
+  | { commitmentTooNew: null }
+  | { commitmentExpired: null }
+  | { tooManyLabels: null }
+  | { overflow: null }
+  | { registryCallFailed: null }
+  | { insufficientPayment: { price: bigint | string; paid: bigint | string } }
+  | { insufficientBalance: { requested: bigint | string; available: bigint | string } }
+  | { tooManyPayees: null }
+  | { invalidShares: { total: number } }
+  | { nothingToRelease: null }
+  | { belowExistentialDeposit: { amount: bigint | string; existential_deposit: bigint | string } }
+  | { insufficientProgramBalance: { requested: bigint | string; available: bigint | string } }
+  | { transferFailed: null }
+  | { tokenNotFound: null }
+  | { notTokenOwnerOrApproved: null }
+  | { transferToZeroAddress: null }
+  | { invalidLabel: LabelError }
+  | { confusableName: { with: number[] } }
+  | { invalidDiscount: { discount: number } }
+  | { nameInAuction: null }
+  | { auctionAlreadyStarted: null }
+  | { auctionNotFound: null }
+  | { notInRevealPeriod: null }
+  | { auctionNotEnded: null }
+  | { noValidBid: null }
+  | { depositTooLow: { minimum: bigint | string } }
+  | { tooManySealedBids: null }
+  | { bidNotReclaimable: null };
+
+export type LabelError =
+  | { invalidUtf8: null }
+  | { tooShort: null }
+  | { containsDot: null }
+  | { disallowedCodePoint: number };
+
+export interface Auction {
+  reveal_at: bigint | string;
+  ends_at: bigint | string;
+  highest: [ActorId, bigint | string, bigint | string] | null;
+  second: bigint | string;
+}
+
+export interface PriceBreakdown {
+  base: bigint | string;
+  premium: bigint | string;
+  discount: bigint | string;
+  expiry_premium: bigint | string;
+}
+
+export interface IoRegistrarState {
+  registry: ActorId;
+  tld_node: bigint | string;
+  hash_scheme: HashScheme;
+  expires: Array<[number[], bigint | string]>;
+  next_cursor: number[] | null;
+  reserved: number[][];
+  price_tiers: PriceTiers;
+  expiry_premium: ExpiryPremium;
   min_commit_age: bigint | string;
   max_commit_age: bigint | string;
   grace_period: bigint | string;
+  controller: ActorId;
+  balance: bigint | string;
+  payees: Array<[ActorId, number]>;
+  bidding_period: bigint | string;
+  reveal_period: bigint | string;
+}
+
+export interface SealedBid {
+  bidder: ActorId;
+  deposit: bigint | string;
+  sealed_at: bigint | string;
 }
 
 export type RegistrarEvent =
-  | { CommitSubmitted: { commitment: [string, number[]]; timestamp: bigint | string } }
-  | { NameRegistered: { name: number[]; owner: ActorId; expires: bigint | string; cost: bigint | string } }
-  | { NameRenewed: { name: number[]; expires: bigint | string; cost: bigint | string } }
-  | { PricesSet: { base: bigint | string; premium: bigint | string } }
-  | { CommitAgesSet: { min: bigint | string; max: bigint | string } }
-  | { GracePeriodSet: { grace: bigint | string } }
-  | { NamesReserved: { labels: number[][] } }
-  | { Withdrawn: { to: ActorId; amount: bigint | string } };
+  | { commitSubmitted: { commitment: `0x${string}`; timestamp: bigint | string } }
+  | { nameRegistered: { name: number[]; owner: ActorId; expires: bigint | string; cost: bigint | string } }
+  | { nameRenewed: { name: number[]; expires: bigint | string; cost: bigint | string } }
+  | { priceTiersSet: { tiers: PriceTiers } }
+  | { commitAgesSet: { min: bigint | string; max: bigint | string } }
+  | { gracePeriodSet: { grace: bigint | string } }
+  | { namesReserved: { labels: number[][] } }
+  | { withdrawn: { to: ActorId; amount: bigint | string } }
+  | { payeesSet: { payees: Array<[ActorId, number]> } }
+  | { transfer: { from: ActorId; to: ActorId; token_id: bigint | string } }
+  | { approval: { owner: ActorId; approved: ActorId; token_id: bigint | string } }
+  | { approvalForAll: { owner: ActorId; operator: ActorId; approved: boolean } }
+  | { nameReclaimed: { token_id: bigint | string; owner: ActorId } }
+  | { confusableAllowed: { name: number[]; allowed: boolean } }
+  | { expiryPremiumSet: { premium: ExpiryPremium } }
+  | { auctionStarted: { labelhash: bigint | string; reveal_at: bigint | string; ends_at: bigint | string } }
+  | { bidSealed: { hash: `0x${string}`; bidder: ActorId; deposit: bigint | string } }
+  | { bidRevealed: { name: number[]; bidder: ActorId; value: bigint | string; accepted: boolean } }
+  | { auctionFinalized: { name: number[]; winner: ActorId | null; price: bigint | string } }
+  | { auctionPeriodsSet: { bidding: bigint | string; reveal: bigint | string } }
+  | { bidReclaimed: { hash: `0x${string}`; bidder: ActorId; deposit: bigint | string } };
 
 const types = {
   InitRegistrar: {
     registry: '[u8;32]',
     tld_node: 'U256',
+    hash_scheme: 'HashScheme',
     controller: '[u8;32]',
-    base_price: 'u128',
-    premium_price: 'u128',
+    price_tiers: 'PriceTiers',
+    min_commit_age: 'u64',
+    max_commit_age: 'u64',
+    grace_period: 'u64',
+  },
+  HashScheme: {
+    _enum: ['Blake2', 'Keccak'],
+  },
+  PriceTiers: {
+    per_year: '[u128;5]',
+    multi_year_discount: 'u16',
+  },
+  ExpiryPremium: {
+    start: 'u128',
+    half_life: 'u64',
+    window: 'u64',
+  },
+  RegistrarError: {
+    _enum: {
+      NotController: 'Null',
+      NameTooLong: 'Null',
+      NameReserved: 'Null',
+      NameNotAvailable: 'Null',
+      NameNotRenewable: 'Null',
+      AddrRequiresResolver: 'Null',
+      CommitmentAlreadyExists: 'Null',
+      TooManyCommitments: 'Null',
+      NoValidCommitment: 'Null',
+      CommitmentTooNew: 'Null',
+      CommitmentExpired: 'Null',
+      TooManyLabels: 'Null',
+      Overflow: 'Null',
+      RegistryCallFailed: 'Null',
+      InsufficientPayment: {
+        price: 'u128',
+        paid: 'u128',
+      },
+      InsufficientBalance: {
+        requested: 'u128',
+        available: 'u128',
+      },
+      TooManyPayees: 'Null',
+      InvalidShares: {
+        total: 'u32',
+      },
+      NothingToRelease: 'Null',
+      BelowExistentialDeposit: {
+        amount: 'u128',
+        existential_deposit: 'u128',
+      },
+      InsufficientProgramBalance: {
+        requested: 'u128',
+        available: 'u128',
+      },
+      TransferFailed: 'Null',
+      TokenNotFound: 'Null',
+      NotTokenOwnerOrApproved: 'Null',
+      TransferToZeroAddress: 'Null',
+      InvalidLabel: 'LabelError',
+      ConfusableName: {
+        with: 'Vec<u8>',
+      },
+      InvalidDiscount: {
+        discount: 'u16',
+      },
+      NameInAuction: 'Null',
+      AuctionAlreadyStarted: 'Null',
+      AuctionNotFound: 'Null',
+      NotInRevealPeriod: 'Null',
+      AuctionNotEnded: 'Null',
+      NoValidBid: 'Null',
+      DepositTooLow: {
+        minimum: 'u128',
+      },
+      TooManySealedBids: 'Null',
+      BidNotReclaimable: 'Null',
+    },
+  },
+  LabelError: {
+    _enum: {
+      InvalidUtf8: 'Null',
+      TooShort: 'Null',
+      ContainsDot: 'Null',
+      DisallowedCodePoint: 'u32',
+    },
+  },
+  Auction: {
+    reveal_at: 'u64',
+    ends_at: 'u64',
+    highest: 'Option<([u8;32], u128, u128)>',
+    second: 'u128',
+  },
+  PriceBreakdown: {
+    base: 'u128',
+    premium: 'u128',
+    discount: 'u128',
+    expiry_premium: 'u128',
+  },
+  IoRegistrarState: {
+    registry: '[u8;32]',
+    tld_node: 'U256',
+    hash_scheme: 'HashScheme',
+    expires: 'Vec<(Vec<u8>, u64)>',
+    next_cursor: 'Option<Vec<u8>>',
+    reserved: 'Vec<Vec<u8>>',
+    price_tiers: 'PriceTiers',
+    expiry_premium: 'ExpiryPremium',
     min_commit_age: 'u64',
     max_commit_age: 'u64',
     grace_period: 'u64',
+    controller: '[u8;32]',
+    balance: 'u128',
+    payees: 'Vec<([u8;32], u16)>',
+    bidding_period: 'u64',
+    reveal_period: 'u64',
+  },
+  SealedBid: {
+    bidder: '[u8;32]',
+    deposit: 'u128',
+    sealed_at: 'u64',
   },
   CommitSubmitted: {
     commitment: '[u8;32]',
@@ -52,9 +277,8 @@ const types = {
     expires: 'u64',
     cost: 'u128',
   },
-  PricesSet: {
-    base: 'u128',
-    premium: 'u128',
+  PriceTiersSet: {
+    tiers: 'PriceTiers',
   },
   CommitAgesSet: {
     min: 'u64',
@@ -70,16 +294,88 @@ const types = {
     to: '[u8;32]',
     amount: 'u128',
   },
+  PayeesSet: {
+    payees: 'Vec<([u8;32], u16)>',
+  },
+  Transfer: {
+    from: '[u8;32]',
+    to: '[u8;32]',
+    token_id: 'U256',
+  },
+  Approval: {
+    owner: '[u8;32]',
+    approved: '[u8;32]',
+    token_id: 'U256',
+  },
+  ApprovalForAll: {
+    owner: '[u8;32]',
+    operator: '[u8;32]',
+    approved: 'bool',
+  },
+  NameReclaimed: {
+    token_id: 'U256',
+    owner: '[u8;32]',
+  },
+  ConfusableAllowed: {
+    name: 'Vec<u8>',
+    allowed: 'bool',
+  },
+  ExpiryPremiumSet: {
+    premium: 'ExpiryPremium',
+  },
+  AuctionStarted: {
+    labelhash: 'U256',
+    reveal_at: 'u64',
+    ends_at: 'u64',
+  },
+  BidSealed: {
+    hash: '[u8;32]',
+    bidder: '[u8;32]',
+    deposit: 'u128',
+  },
+  BidRevealed: {
+    name: 'Vec<u8>',
+    bidder: '[u8;32]',
+    value: 'u128',
+    accepted: 'bool',
+  },
+  AuctionFinalized: {
+    name: 'Vec<u8>',
+    winner: 'Option<[u8;32]>',
+    price: 'u128',
+  },
+  AuctionPeriodsSet: {
+    bidding: 'u64',
+    reveal: 'u64',
+  },
+  BidReclaimed: {
+    hash: '[u8;32]',
+    bidder: '[u8;32]',
+    deposit: 'u128',
+  },
   RegistrarEvent: {
     _enum: {
       CommitSubmitted: 'CommitSubmitted',
       NameRegistered: 'NameRegistered',
       NameRenewed: 'NameRenewed',
-      PricesSet: 'PricesSet',
+      PriceTiersSet: 'PriceTiersSet',
       CommitAgesSet: 'CommitAgesSet',
       GracePeriodSet: 'GracePeriodSet',
       NamesReserved: 'NamesReserved',
       Withdrawn: 'Withdrawn',
+      PayeesSet: 'PayeesSet',
+      Transfer: 'Transfer',
+      Approval: 'Approval',
+      ApprovalForAll: 'ApprovalForAll',
+      NameReclaimed: 'NameReclaimed',
+      ConfusableAllowed: 'ConfusableAllowed',
+      ExpiryPremiumSet: 'ExpiryPremiumSet',
+      AuctionStarted: 'AuctionStarted',
+      BidSealed: 'BidSealed',
+      BidRevealed: 'BidRevealed',
+      AuctionFinalized: 'AuctionFinalized',
+      AuctionPeriodsSet: 'AuctionPeriodsSet',
+      BidReclaimed: 'BidReclaimed',
     },
   },
 };
@@ -142,15 +438,79 @@ export class Program {
 export class Service {
   constructor(private _program: Program) {}
 
-  public commit(commitment: [number, number[]]): TransactionBuilder<RegistrarEvent> {
+  public approve(
+    approved: ActorId,
+    token_id: bigint | string | number,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'Approve', approved, token_id],
+      '(String, String, [u8;32], U256)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public commit(
+    commitment: `0x${string}`,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'Commit', commitment],
       '(String, String, [u8;32])',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public finalizeAuction(
+    name: number[],
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'FinalizeAuction', name],
+      '(String, String, Vec<u8>)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public reclaim(
+    token_id: bigint | string | number,
+    owner: ActorId,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'Reclaim', token_id, owner],
+      '(String, String, U256, [u8;32])',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public reclaimBid(
+    hash: `0x${string}`,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'ReclaimBid', hash],
+      '(String, String, [u8;32])',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
@@ -159,18 +519,34 @@ export class Service {
     name: number[],
     owner: ActorId,
     duration: bigint | string | number,
-    secret: [number, number[]],
-    salt: [number, number[]],
+    secret: `0x${string}`,
+    salt: `0x${string}`,
     resolver: ActorId | null,
-  ): TransactionBuilder<RegistrarEvent> {
+    addr: ActorId | null,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'Register', name, owner, duration, secret, salt, resolver, addr],
+      '(String, String, Vec<u8>, [u8;32], u64, [u8;32], [u8;32], Option<[u8;32]>, Option<[u8;32]>)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public release(
+    payee: ActorId,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
-      ['Service', 'Register', name, owner, duration, secret, salt, resolver],
-      '(String, String, Vec<u8>, [u8;32], u64, [u8;32], [u8;32], Option<[u8;32]>)',
-      'RegistrarEvent',
+      ['Service', 'Release', payee],
+      '(String, String, [u8;32])',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
@@ -178,28 +554,95 @@ export class Service {
   public renew(
     name: number[],
     duration: bigint | string | number,
-  ): TransactionBuilder<RegistrarEvent> {
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'Renew', name, duration],
       '(String, String, Vec<u8>, u64)',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
 
-  public reserveNames(labels: number[][]): TransactionBuilder<RegistrarEvent> {
+  public reserveNames(
+    labels: number[][],
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'ReserveNames', labels],
       '(String, String, Vec<Vec<u8>>)',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public revealBid(
+    name: number[],
+    value: bigint | string | number,
+    salt: `0x${string}`,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'RevealBid', name, value, salt],
+      '(String, String, Vec<u8>, u128, [u8;32])',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public sealBid(
+    hash: `0x${string}`,
+    deposit: bigint | string | number,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SealBid', hash, deposit],
+      '(String, String, [u8;32], u128)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setApprovalForAll(
+    operator: ActorId,
+    approved: boolean,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetApprovalForAll', operator, approved],
+      '(String, String, [u8;32], bool)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setAuctionPeriods(
+    bidding: bigint | string | number,
+    reveal: bigint | string | number,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetAuctionPeriods', bidding, reveal],
+      '(String, String, u64, u64)',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
@@ -207,46 +650,122 @@ export class Service {
   public setCommitAges(
     min: bigint | string | number,
     max: bigint | string | number,
-  ): TransactionBuilder<RegistrarEvent> {
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'SetCommitAges', min, max],
       '(String, String, u64, u64)',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setConfusableAllowed(
+    name: number[],
+    allowed: boolean,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetConfusableAllowed', name, allowed],
+      '(String, String, Vec<u8>, bool)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setExpiryPremium(
+    premium: ExpiryPremium,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetExpiryPremium', premium],
+      '(String, String, ExpiryPremium)',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
 
   public setGracePeriod(
     grace: bigint | string | number,
-  ): TransactionBuilder<RegistrarEvent> {
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'SetGracePeriod', grace],
       '(String, String, u64)',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setPayees(
+    payees: Array<[ActorId, number]>,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetPayees', payees],
+      '(String, String, Vec<([u8;32], u16)>)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public setPriceTiers(
+    tiers: PriceTiers,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'SetPriceTiers', tiers],
+      '(String, String, PriceTiers)',
+      'Result<RegistrarEvent, RegistrarError>',
+      this._program.programId,
+    );
+  }
+
+  public startAuction(
+    labelhash: bigint | string | number,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
+    if (!this._program.programId) throw new Error('Program ID is not set');
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
+      this._program.api,
+      this._program.registry,
+      'send_message',
+      ['Service', 'StartAuction', labelhash],
+      '(String, String, U256)',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
 
-  public setPrices(
-    base: bigint | string | number,
-    premium: bigint | string | number,
-  ): TransactionBuilder<RegistrarEvent> {
+  public transfer(
+    to: ActorId,
+    token_id: bigint | string | number,
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
-      ['Service', 'SetPrices', base, premium],
-      '(String, String, u128, u128)',
-      'RegistrarEvent',
+      ['Service', 'Transfer', to, token_id],
+      '(String, String, [u8;32], U256)',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
@@ -254,26 +773,26 @@ export class Service {
   public withdraw(
     to: ActorId,
     amount: bigint | string | number,
-  ): TransactionBuilder<RegistrarEvent> {
+  ): TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }> {
     if (!this._program.programId) throw new Error('Program ID is not set');
-    return new TransactionBuilder<RegistrarEvent>(
+    return new TransactionBuilder<{ ok: RegistrarEvent } | { err: RegistrarError }>(
       this._program.api,
       this._program.registry,
       'send_message',
       ['Service', 'Withdraw', to, amount],
       '(String, String, [u8;32], u128)',
-      'RegistrarEvent',
+      'Result<RegistrarEvent, RegistrarError>',
       this._program.programId,
     );
   }
 
-  public async available(
-    name: number[],
+  public async auctionOf(
+    labelhash: bigint | string | number,
     originAddress?: string,
     value?: number | string | bigint,
     atBlock?: `0x${string}`,
-  ): Promise<boolean> {
-    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'Available', name]).toHex();
+  ): Promise<Auction | null> {
+    const payload = this._program.registry.createType('(String, String, U256)', ['Service', 'AuctionOf', labelhash]).toHex();
     const reply = await this._program.api.message.calculateReply({
       destination: this._program.programId,
       origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
@@ -283,17 +802,17 @@ export class Service {
       at: atBlock,
     });
     if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
-    const result = this._program.registry.createType('(String, String, bool)', reply.payload);
-    return result[2].valueOf() as boolean;
+    const result = this._program.registry.createType('(String, String, Option<Auction>)', reply.payload);
+    return result[2].toJSON() as unknown as Auction | null;
   }
 
-  public async expiryOf(
+  public async available(
     name: number[],
     originAddress?: string,
     value?: number | string | bigint,
     atBlock?: `0x${string}`,
-  ): Promise<bigint | string | null> {
-    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'ExpiryOf', name]).toHex();
+  ): Promise<{ ok: boolean } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'Available', name]).toHex();
     const reply = await this._program.api.message.calculateReply({
       destination: this._program.programId,
       origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
@@ -303,18 +822,17 @@ export class Service {
       at: atBlock,
     });
     if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
-    const result = this._program.registry.createType('(String, String, Option<u64>)', reply.payload);
-    return result[2].toJSON() as bigint | string | null;
+    const result = this._program.registry.createType('(String, String, Result<bool, RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: boolean } | { err: RegistrarError };
   }
 
-  public async price(
-    name: number[],
-    duration: bigint | string | number,
+  public async balanceOf(
+    owner: ActorId,
     originAddress?: string,
     value?: number | string | bigint,
     atBlock?: `0x${string}`,
-  ): Promise<bigint | string> {
-    const payload = this._program.registry.createType('(String, String, Vec<u8>, u64)', ['Service', 'Price', name, duration]).toHex();
+  ): Promise<bigint> {
+    const payload = this._program.registry.createType('(String, String, [u8;32])', ['Service', 'BalanceOf', owner]).toHex();
     const reply = await this._program.api.message.calculateReply({
       destination: this._program.programId,
       origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
@@ -324,47 +842,419 @@ export class Service {
       at: atBlock,
     });
     if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
-    const result = this._program.registry.createType('(String, String, u128)', reply.payload);
+    const result = this._program.registry.createType('(String, String, u64)', reply.payload);
     return result[2].toBigInt();
   }
 
-  public subscribeToCommitSubmittedEvent(
-    callback: (data: { commitment: [string, number[]]; timestamp: bigint | string }) => void | Promise<void>
-  ): Promise<() => void> {
-    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
-      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
-      const payload = message.payload.toHex();
-      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'CommitSubmitted') {
-        void Promise.resolve(callback(
-          this._program.registry.createType('(String, String, CommitSubmitted)', message.payload)[2].toJSON() as {
-            commitment: [string, number[]];
-            timestamp: bigint | string;
-          }
-        )).catch(console.error);
-      }
+  public async bidHash(
+    name: number[],
+    bidder: ActorId,
+    value: bigint | string | number,
+    salt: `0x${string}`,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<{ ok: `0x${string}` } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>, [u8;32], u128, [u8;32])', ['Service', 'BidHash', name, bidder, value, salt]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
     });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Result<[u8;32], RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: `0x${string}` } | { err: RegistrarError };
   }
 
-  public subscribeToNameRegisteredEvent(
-    callback: (data: { name: number[]; owner: ActorId; expires: bigint | string; cost: bigint | string }) => void | Promise<void>
-  ): Promise<() => void> {
-    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
-      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
-      const payload = message.payload.toHex();
-      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'NameRegistered') {
-        void Promise.resolve(callback(
-          this._program.registry.createType('(String, String, NameRegistered)', message.payload)[2].toJSON() as {
-            name: number[];
-            owner: ActorId;
-            expires: bigint | string;
-            cost: bigint | string;
-          }
-        )).catch(console.error);
-      }
+  public async commitmentVersion(
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<number> {
+    const payload = this._program.registry.createType('(String, String)', ['Service', 'CommitmentVersion']).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
     });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, u8)', reply.payload);
+    return result[2].toNumber();
   }
 
-  public subscribeToNameRenewedEvent(
+  public async commitsPage(
+    cursor: `0x${string}` | null,
+    limit: number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<[Array<[`0x${string}`, bigint | string]>, `0x${string}` | null]> {
+    const payload = this._program.registry.createType('(String, String, Option<[u8;32]>, u32)', ['Service', 'CommitsPage', cursor, limit]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, (Vec<([u8;32], u64)>, Option<[u8;32]>))', reply.payload);
+    return result[2].toJSON() as unknown as [Array<[`0x${string}`, bigint | string]>, `0x${string}` | null];
+  }
+
+  public async confusableWith(
+    name: number[],
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<{ ok: number[] | null } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'ConfusableWith', name]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Result<Option<Vec<u8>>, RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: number[] | null } | { err: RegistrarError };
+  }
+
+  public async expiriesPage(
+    cursor: number[] | null,
+    limit: number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<[Array<[number[], bigint | string]>, number[] | null]> {
+    const payload = this._program.registry.createType('(String, String, Option<Vec<u8>>, u32)', ['Service', 'ExpiriesPage', cursor, limit]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>))', reply.payload);
+    return result[2].toJSON() as unknown as [Array<[number[], bigint | string]>, number[] | null];
+  }
+
+  public async expiryOf(
+    name: number[],
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<bigint | string | null> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'ExpiryOf', name]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Option<u64>)', reply.payload);
+    return result[2].toJSON() as unknown as bigint | string | null;
+  }
+
+  public async getApproved(
+    token_id: bigint | string | number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<ActorId | null> {
+    const payload = this._program.registry.createType('(String, String, U256)', ['Service', 'GetApproved', token_id]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Option<[u8;32]>)', reply.payload);
+    return result[2].toJSON() as unknown as ActorId | null;
+  }
+
+  public async isApprovedForAll(
+    owner: ActorId,
+    operator: ActorId,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<boolean> {
+    const payload = this._program.registry.createType('(String, String, [u8;32], [u8;32])', ['Service', 'IsApprovedForAll', owner, operator]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, bool)', reply.payload);
+    return result[2].valueOf() as boolean;
+  }
+
+  public async labelsOf(
+    owner: ActorId,
+    cursor: number[] | null,
+    limit: number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<[Array<[number[], bigint | string]>, number[] | null]> {
+    const payload = this._program.registry.createType('(String, String, [u8;32], Option<Vec<u8>>, u32)', ['Service', 'LabelsOf', owner, cursor, limit]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>))', reply.payload);
+    return result[2].toJSON() as unknown as [Array<[number[], bigint | string]>, number[] | null];
+  }
+
+  public async makeCommitment(
+    name: number[],
+    owner: ActorId,
+    secret: `0x${string}`,
+    salt: `0x${string}`,
+    duration: bigint | string | number,
+    resolver: ActorId | null,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<{ ok: `0x${string}` } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>, [u8;32], [u8;32], [u8;32], u64, Option<[u8;32]>)', ['Service', 'MakeCommitment', name, owner, secret, salt, duration, resolver]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Result<[u8;32], RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: `0x${string}` } | { err: RegistrarError };
+  }
+
+  public async minBidDeposit(
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<bigint> {
+    const payload = this._program.registry.createType('(String, String)', ['Service', 'MinBidDeposit']).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, u128)', reply.payload);
+    return result[2].toBigInt();
+  }
+
+  public async ownerOfToken(
+    token_id: bigint | string | number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<ActorId | null> {
+    const payload = this._program.registry.createType('(String, String, U256)', ['Service', 'OwnerOfToken', token_id]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Option<[u8;32]>)', reply.payload);
+    return result[2].toJSON() as unknown as ActorId | null;
+  }
+
+  public async payeeBalance(
+    payee: ActorId,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<bigint> {
+    const payload = this._program.registry.createType('(String, String, [u8;32])', ['Service', 'PayeeBalance', payee]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, u128)', reply.payload);
+    return result[2].toBigInt();
+  }
+
+  public async payees(
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<Array<[ActorId, number]>> {
+    const payload = this._program.registry.createType('(String, String)', ['Service', 'Payees']).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Vec<([u8;32], u16)>)', reply.payload);
+    return result[2].toJSON() as unknown as Array<[ActorId, number]>;
+  }
+
+  public async price(
+    name: number[],
+    duration: bigint | string | number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<{ ok: PriceBreakdown } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>, u64)', ['Service', 'Price', name, duration]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Result<PriceBreakdown, RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: PriceBreakdown } | { err: RegistrarError };
+  }
+
+  public async queryState(
+    cursor: number[] | null,
+    limit: number,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<IoRegistrarState> {
+    const payload = this._program.registry.createType('(String, String, Option<Vec<u8>>, u32)', ['Service', 'QueryState', cursor, limit]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, IoRegistrarState)', reply.payload);
+    return result[2].toJSON() as unknown as IoRegistrarState;
+  }
+
+  public async sealedBid(
+    hash: `0x${string}`,
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<SealedBid | null> {
+    const payload = this._program.registry.createType('(String, String, [u8;32])', ['Service', 'SealedBid', hash]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Option<SealedBid>)', reply.payload);
+    return result[2].toJSON() as unknown as SealedBid | null;
+  }
+
+  public async tokenIdOf(
+    name: number[],
+    originAddress?: string,
+    value?: number | string | bigint,
+    atBlock?: `0x${string}`,
+  ): Promise<{ ok: bigint | string } | { err: RegistrarError }> {
+    const payload = this._program.registry.createType('(String, String, Vec<u8>)', ['Service', 'TokenIdOf', name]).toHex();
+    const reply = await this._program.api.message.calculateReply({
+      destination: this._program.programId,
+      origin: originAddress ? decodeAddress(originAddress) : ZERO_ADDRESS,
+      payload,
+      value: value ?? 0,
+      gasLimit: this._program.api.blockGasLimit.toBigInt(),
+      at: atBlock,
+    });
+    if (!reply.code.isSuccess) throw new Error(this._program.registry.createType('String', reply.payload).toString());
+    const result = this._program.registry.createType('(String, String, Result<U256, RegistrarError>)', reply.payload);
+    return result[2].toJSON() as unknown as { ok: bigint | string } | { err: RegistrarError };
+  }
+
+  public subscribeToCommitSubmittedEvent(
+    callback: (data: { commitment: `0x${string}`; timestamp: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'CommitSubmitted') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, CommitSubmitted)', message.payload)[2].toJSON() as {
+            commitment: `0x${string}`;
+            timestamp: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToNameRegisteredEvent(
+    callback: (data: { name: number[]; owner: ActorId; expires: bigint | string; cost: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'NameRegistered') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, NameRegistered)', message.payload)[2].toJSON() as {
+            name: number[];
+            owner: ActorId;
+            expires: bigint | string;
+            cost: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToNameRenewedEvent(
     callback: (data: { name: number[]; expires: bigint | string; cost: bigint | string }) => void | Promise<void>
   ): Promise<() => void> {
     return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
@@ -382,17 +1272,16 @@ export class Service {
     });
   }
 
-  public subscribeToPricesSetEvent(
-    callback: (data: { base: bigint | string; premium: bigint | string }) => void | Promise<void>
+  public subscribeToPriceTiersSetEvent(
+    callback: (data: { tiers: PriceTiers }) => void | Promise<void>
   ): Promise<() => void> {
     return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
       if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
       const payload = message.payload.toHex();
-      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'PricesSet') {
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'PriceTiersSet') {
         void Promise.resolve(callback(
-          this._program.registry.createType('(String, String, PricesSet)', message.payload)[2].toJSON() as {
-            base: bigint | string;
-            premium: bigint | string;
+          this._program.registry.createType('(String, String, PriceTiersSet)', message.payload)[2].toJSON() as {
+            tiers: PriceTiers;
           }
         )).catch(console.error);
       }
@@ -464,4 +1353,232 @@ export class Service {
       }
     });
   }
+
+  public subscribeToPayeesSetEvent(
+    callback: (data: { payees: Array<[ActorId, number]> }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'PayeesSet') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, PayeesSet)', message.payload)[2].toJSON() as {
+            payees: Array<[ActorId, number]>;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToTransferEvent(
+    callback: (data: { from: ActorId; to: ActorId; token_id: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'Transfer') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, Transfer)', message.payload)[2].toJSON() as {
+            from: ActorId;
+            to: ActorId;
+            token_id: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToApprovalEvent(
+    callback: (data: { owner: ActorId; approved: ActorId; token_id: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'Approval') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, Approval)', message.payload)[2].toJSON() as {
+            owner: ActorId;
+            approved: ActorId;
+            token_id: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToApprovalForAllEvent(
+    callback: (data: { owner: ActorId; operator: ActorId; approved: boolean }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'ApprovalForAll') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, ApprovalForAll)', message.payload)[2].toJSON() as {
+            owner: ActorId;
+            operator: ActorId;
+            approved: boolean;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToNameReclaimedEvent(
+    callback: (data: { token_id: bigint | string; owner: ActorId }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'NameReclaimed') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, NameReclaimed)', message.payload)[2].toJSON() as {
+            token_id: bigint | string;
+            owner: ActorId;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToConfusableAllowedEvent(
+    callback: (data: { name: number[]; allowed: boolean }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'ConfusableAllowed') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, ConfusableAllowed)', message.payload)[2].toJSON() as {
+            name: number[];
+            allowed: boolean;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToExpiryPremiumSetEvent(
+    callback: (data: { premium: ExpiryPremium }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'ExpiryPremiumSet') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, ExpiryPremiumSet)', message.payload)[2].toJSON() as {
+            premium: ExpiryPremium;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToAuctionStartedEvent(
+    callback: (data: { labelhash: bigint | string; reveal_at: bigint | string; ends_at: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'AuctionStarted') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, AuctionStarted)', message.payload)[2].toJSON() as {
+            labelhash: bigint | string;
+            reveal_at: bigint | string;
+            ends_at: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToBidSealedEvent(
+    callback: (data: { hash: `0x${string}`; bidder: ActorId; deposit: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'BidSealed') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, BidSealed)', message.payload)[2].toJSON() as {
+            hash: `0x${string}`;
+            bidder: ActorId;
+            deposit: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToBidRevealedEvent(
+    callback: (data: { name: number[]; bidder: ActorId; value: bigint | string; accepted: boolean }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'BidRevealed') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, BidRevealed)', message.payload)[2].toJSON() as {
+            name: number[];
+            bidder: ActorId;
+            value: bigint | string;
+            accepted: boolean;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToAuctionFinalizedEvent(
+    callback: (data: { name: number[]; winner: ActorId | null; price: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'AuctionFinalized') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, AuctionFinalized)', message.payload)[2].toJSON() as {
+            name: number[];
+            winner: ActorId | null;
+            price: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToAuctionPeriodsSetEvent(
+    callback: (data: { bidding: bigint | string; reveal: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'AuctionPeriodsSet') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, AuctionPeriodsSet)', message.payload)[2].toJSON() as {
+            bidding: bigint | string;
+            reveal: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
+
+  public subscribeToBidReclaimedEvent(
+    callback: (data: { hash: `0x${string}`; bidder: ActorId; deposit: bigint | string }) => void | Promise<void>
+  ): Promise<() => void> {
+    return this._program.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+      if (!message.source.eq(this._program.programId) || !message.destination.eq(ZERO_ADDRESS)) return;
+      const payload = message.payload.toHex();
+      if (getServiceNamePrefix(payload) === 'Service' && getFnNamePrefix(payload) === 'BidReclaimed') {
+        void Promise.resolve(callback(
+          this._program.registry.createType('(String, String, BidReclaimed)', message.payload)[2].toJSON() as {
+            hash: `0x${string}`;
+            bidder: ActorId;
+            deposit: bigint | string;
+          }
+        )).catch(console.error);
+      }
+    });
+  }
 }
@@ -47,12 +47,23 @@ export function NameAvailabilityCard() {
       try {
         const bytes = Array.from(nameToBytes(name.trim()));
         // 1) available?
-        const isAvail = await svc.available(bytes, account?.address);
+        const avail = await svc.available(bytes, account?.address);
         if (cancel) return;
+        if ('err' in avail) {
+          // nombre que no se puede registrar (inválido o demasiado largo)
+          setStatus('reserved_or_invalid');
+          setExpiresAt(null);
+          setPricePlancks(null);
+          return;
+        }
+        const isAvail = avail.ok;
 
         // 2) siempre calculamos precio estimado para 1 año
         const price = await svc.price(bytes, ONE_YEAR_MS, account?.address);
-        if (!cancel) setPricePlancks(typeof price === 'bigint' ? price : BigInt(price as any));
+        if (!cancel && 'ok' in price) {
+          const { base, premium, discount, expiry_premium } = price.ok;
+          setPricePlancks(BigInt(base) + BigInt(premium) - BigInt(discount) + BigInt(expiry_premium));
+        }
 
         if (isAvail) {
           if (!cancel) {
@@ -2,42 +2,60 @@ import React, { useCallback, useEffect, useMemo, useState } from 'react';
 import { useAccount, useAlert, useApi } from '@gear-js/react-hooks';
 import { web3Enable, web3FromSource } from '@polkadot/extension-dapp';
 import { decodeAddress } from '@polkadot/util-crypto';
-import { TransactionBuilder } from 'sails-js';
-import { Program, Service } from '@/hocs/lib';
+import { ActorId, PriceBreakdown, Program, RegistrarError, Service } from '@/hocs/lib';
 import { blake2b } from '@noble/hashes/blake2b';
 
 const PROGRAM_ID = '0xa28014929e22e705c5bf53f9651d514b87abf9b274763d731b3d9bae54ab3d6b';
+// Resolver set on new names, with an `addr` record pointing at the owner; optional.
+const RESOLVER_ID = (import.meta.env.VITE_RESOLVER_ID as ActorId | undefined) || null;
 
 const MIN_COMMIT_AGE_MS = 60_000;
 const DEFAULT_DURATION_MS = 365n * 24n * 60n * 60n * 1000n;
+// Layout version of `vns_common::commitment`, as reported by the `CommitmentVersion` query
+const COMMITMENT_VERSION = 1;
 
 const te = new TextEncoder();
+// The registrar lowercases and NFC-normalizes names, and commitments are to the normalized name.
+const normalizeName = (name: string) => name.trim().toLowerCase().normalize('NFC');
 const nameToBytes = (name: string) => te.encode(name);
 
 // Uint8Array -> hex string 0x...
-const u8aToHex = (u8: Uint8Array) =>
-  '0x' + Array.from(u8).map((b) => b.toString(16).padStart(2, '0')).join('');
+const u8aToHex = (u8: Uint8Array): `0x${string}` =>
+  `0x${Array.from(u8).map((b) => b.toString(16).padStart(2, '0')).join('')}`;
 
-/** commitment = blake2b-256(name || owner || secret || salt) */
+/**
+ * commitment = blake2b-256(SCALE(version, name, owner, secret, salt, duration, resolver)),
+ * see `vns_common::commitment`
+ */
 function makeCommitment(
-  name: string,
-  ownerBytes32: Uint8Array,
-  secret32: Uint8Array,
-  salt32: Uint8Array
+  program: Program,
+  name: number[],
+  owner: ActorId,
+  secret: `0x${string}`,
+  salt: `0x${string}`,
+  duration: bigint,
+  resolver: ActorId | null,
 ): Uint8Array {
-  const n = nameToBytes(name);
-  const preimage = new Uint8Array(n.length + 32 + 32 + 32);
-  let off = 0;
-  preimage.set(n, off);
-  off += n.length;
-  preimage.set(ownerBytes32, off);
-  off += 32;
-  preimage.set(secret32, off);
-  off += 32;
-  preimage.set(salt32, off);
+  const preimage = program.registry
+    .createType('(u8, Vec<u8>, [u8;32], [u8;32], [u8;32], u64, Option<[u8;32]>)', [
+      COMMITMENT_VERSION,
+      name,
+      owner,
+      secret,
+      salt,
+      duration,
+      resolver,
+    ])
+    .toU8a();
   return blake2b(preimage, { dkLen: 32 });
 }
 
+/** Value to attach to a registration: `base + premium - discount + expiry_premium` */
+const totalPrice = ({ base, premium, discount, expiry_premium }: PriceBreakdown) =>
+  BigInt(base) + BigInt(premium) - BigInt(discount) + BigInt(expiry_premium);
+
+const describeError = (err: RegistrarError) => JSON.stringify(err);
+
 export function ClaimVaraNameCard() {
   const { account } = useAccount();
   const { api, isApiReady } = useApi();
@@ -69,58 +87,53 @@ export function ClaimVaraNameCard() {
       return alert.error('Invalid owner address');
     }
 
-    const secret = crypto.getRandomValues(new Uint8Array(32));
-    const salt = crypto.getRandomValues(new Uint8Array(32));
-
-    const commitment = makeCommitment(name.trim(), ownerBytes32, secret, salt);
-
-    const commitmentHex = u8aToHex(commitment);   
-    const ownerHex32   = u8aToHex(ownerBytes32);  
-    const secretHex    = u8aToHex(secret);       
-    const saltHex      = u8aToHex(salt);         
-   
+    const label = normalizeName(name);
+    const nameBytes = Array.from(nameToBytes(label));
+    const ownerHex32 = u8aToHex(ownerBytes32);
+    const secretHex = u8aToHex(crypto.getRandomValues(new Uint8Array(32)));
+    const saltHex = u8aToHex(crypto.getRandomValues(new Uint8Array(32)));
+    // An `addr` record can only be set together with a resolver.
+    const addr = RESOLVER_ID ? ownerHex32 : null;
 
     try {
       setSending(true);
       setStatus('Submitting commitment…');
 
-      const svc = new Service(new Program(api, PROGRAM_ID));
+      const program = new Program(api, PROGRAM_ID);
+      const svc = new Service(program);
       const injector = await web3FromSource(account.meta.source);
 
-      // 1) commit: pasa hex de 32 bytes
-      const commitTx: TransactionBuilder<unknown> = (svc as any).commit(commitmentHex);
+      // 1) commit to the registration, duration and resolver included
+      const commitment = makeCommitment(program, nameBytes, ownerHex32, secretHex, saltHex, DEFAULT_DURATION_MS, RESOLVER_ID);
+      const commitTx = svc.commit(u8aToHex(commitment));
       commitTx.withAccount(account.decodedAddress, { signer: injector.signer });
       await commitTx.calculateGas();
       const { blockHash: commitBlock, response: commitResp } = await commitTx.signAndSend();
       alert.info(`Commit in block ${commitBlock}`);
-      await commitResp();
+      const committed = await commitResp();
+      if ('err' in committed) throw new Error(`Commit failed: ${describeError(committed.err)}`);
 
       // 2) wait min_commit_age
       setStatus(`Waiting ${Math.ceil(MIN_COMMIT_AGE_MS / 1000)}s before register…`);
       await new Promise((res) => setTimeout(res, MIN_COMMIT_AGE_MS));
 
-      // 3) register: name -> number[], owner/secret/salt -> hex 32 bytes
+      // 3) register, paying the current price; any excess is refunded
       setStatus('Finalizing registration…');
 
-      const nameBytes = Array.from(nameToBytes(name.trim())); 
-
-      const registerTx = (svc as any).register(
-        nameBytes,           
-        ownerHex32,         
-        DEFAULT_DURATION_MS, 
-        secretHex,           
-        saltHex,            
-        null
-      );
+      const price = await svc.price(nameBytes, DEFAULT_DURATION_MS, account.address);
+      if ('err' in price) throw new Error(`Cannot price "${label}": ${describeError(price.err)}`);
 
+      const registerTx = svc.register(nameBytes, ownerHex32, DEFAULT_DURATION_MS, secretHex, saltHex, RESOLVER_ID, addr);
       registerTx.withAccount(account.decodedAddress, { signer: injector.signer });
+      registerTx.withValue(totalPrice(price.ok));
       await registerTx.calculateGas();
       const { blockHash: regBlock, response: regResp } = await registerTx.signAndSend();
       alert.info(`Register in block ${regBlock}`);
-      await regResp();
+      const registered = await regResp();
+      if ('err' in registered) throw new Error(`Registration failed: ${describeError(registered.err)}`);
 
-      setStatus(`✅ Registered “${name}.vara” successfully`);
-      alert.success(`Registered "${name}.vara"`);
+      setStatus(`✅ Registered “${label}.vara” successfully`);
+      alert.success(`Registered "${label}.vara"`);
     } catch (e) {
       const msg = (e as any)?.message ?? 'Transaction failed';
       setStatus(`❌ ${msg}`);
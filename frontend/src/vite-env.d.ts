@@ -6,6 +6,7 @@
 interface ImportMetaEnv {
     readonly VITE_PROGRAMID: `0x${string}`;
     readonly VITE_BACKEND: string;
+    readonly VITE_RESOLVER_ID?: `0x${string}`;
  
   }
   
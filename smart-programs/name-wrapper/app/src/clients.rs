@@ -0,0 +1,9 @@
+/// Client of the registry program, generated from `idls/registry.idl` by build.rs
+pub mod registry_client {
+    include!(concat!(env!("OUT_DIR"), "/registry_client.rs"));
+}
+
+/// Client of the registrar program, generated from `idls/registrar.idl` by build.rs
+pub mod registrar_client {
+    include!(concat!(env!("OUT_DIR"), "/registrar_client.rs"));
+}
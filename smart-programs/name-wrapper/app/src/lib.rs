@@ -0,0 +1,23 @@
+#![no_std]
+use sails_rs::prelude::*;
+pub mod clients;
+pub mod services;
+
+use services::service::{Service, InitNameWrapper};
+
+pub struct Program;
+
+#[program]
+impl Program {
+    /// Construct a new NameWrapper program. It needs no rights in the registry: owners
+    /// approve it as an operator to wrap their names.
+    pub fn new(init: InitNameWrapper) -> Self {
+        Service::seed(init);
+        Self
+    }
+
+    #[export(route = "Service")]
+    pub fn service(&self) -> Service {
+        Service::new()
+    }
+}
@@ -0,0 +1,488 @@
+#![allow(static_mut_refs)]
+
+use sails_rs::{
+    prelude::*,
+    gstd::{exec, msg},
+    collections::HashMap,
+};
+use sails_rs::calls::ActionIo;
+
+use crate::clients::registrar_client::service::io as registrar_io;
+use crate::clients::registry_client::service::io as registry_io;
+
+pub use vns_common::{fuses, HashScheme, Label, Node};
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum NameWrapperEvent {
+    NameWrapped { node: Node, owner: ActorId, fuses: u32, expiry: u64 },
+    NameUnwrapped { node: Node, owner: ActorId },
+    FusesSet { node: Node, fuses: u32, expiry: u64 },
+    Transfer { node: Node, from: ActorId, to: ActorId },
+    ResolverSet { node: Node, resolver: ActorId },
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum NameWrapperError {
+    NotWrapped,
+    NotOwner,
+    /// The operation is prohibited by a burned fuse
+    FuseBurned { fuse: u32 },
+    /// Owner fuses require `PARENT_CANNOT_CONTROL` to be burned first
+    ParentCanControl,
+    /// Other owner fuses require `CANNOT_UNWRAP` to be burned first
+    CanUnwrap,
+    /// `PARENT_CANNOT_CONTROL` requires the parent to have burned `CANNOT_UNWRAP`
+    ParentCanUnwrap,
+    /// The fuses cannot be burned by this caller
+    InvalidFuses,
+    NotRegistered,
+    RegistryCallFailed,
+    RegistrarCallFailed,
+}
+
+/// A name held by the wrapper on behalf of its owner
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct WrappedName {
+    pub owner: ActorId,
+    pub fuses: u32,
+    /// Fuses only hold until this timestamp; afterwards the name reads as having none.
+    pub expiry: u64,
+}
+
+impl WrappedName {
+    /// Fuses in effect at `now`
+    pub fn fuses_at(&self, now: u64) -> u32 {
+        if now <= self.expiry { self.fuses } else { 0 }
+    }
+}
+
+#[derive(Debug, Encode, Decode, Clone, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitNameWrapper {
+    pub registry: ActorId,
+    /// Registrar of `tld_node`, asked for the expiry of wrapped second-level names
+    pub registrar: ActorId,
+    pub tld_node: Node,
+    /// Must match the registry's `hash_scheme`, as labels are hashed here.
+    pub hash_scheme: HashScheme,
+}
+
+#[derive(Debug, Default)]
+pub struct NameWrapperState {
+    pub registry: ActorId,
+    pub registrar: ActorId,
+    pub tld_node: Node,
+    pub hash_scheme: HashScheme,
+    pub wrapped: HashMap<Node, WrappedName>,
+    /// Registrar tokens held for wrapped second-level names, by node
+    pub tokens: HashMap<Node, U256>,
+}
+
+static mut NAME_WRAPPER_STATE: Option<NameWrapperState> = None;
+
+impl NameWrapperState {
+    pub fn state_mut() -> &'static mut NameWrapperState {
+        let s = unsafe { NAME_WRAPPER_STATE.as_mut() };
+        debug_assert!(s.is_some(), "Name wrapper state not initialized");
+        unsafe { s.unwrap_unchecked() }
+    }
+    pub fn state_ref() -> &'static NameWrapperState {
+        let s = unsafe { NAME_WRAPPER_STATE.as_ref() };
+        debug_assert!(s.is_some(), "Name wrapper state not initialized");
+        unsafe { s.unwrap_unchecked() }
+    }
+    /// Wrapped name owned by `caller`
+    pub fn owned_by(&self, node: &Node, caller: &ActorId) -> Result<&WrappedName, NameWrapperError> {
+        let wrapped = self.wrapped.get(node).ok_or(NameWrapperError::NotWrapped)?;
+        if &wrapped.owner != caller {
+            return Err(NameWrapperError::NotOwner);
+        }
+        Ok(wrapped)
+    }
+    /// Forget a wrapped name, along with the token held for it
+    pub fn drop_name(&mut self, node: &Node) {
+        self.wrapped.remove(node);
+        self.tokens.remove(node);
+    }
+}
+
+/// Fail with `FuseBurned` if `fuse` is among `fuses`
+fn check_fuse(fuses: u32, fuse: u32) -> Result<(), NameWrapperError> {
+    if fuses & fuse != 0 {
+        return Err(NameWrapperError::FuseBurned { fuse });
+    }
+    Ok(())
+}
+
+/// Check that burning owner fuses `burn` is allowed on a name currently holding `current`.
+fn check_owner_fuses(current: u32, burn: u32) -> Result<(), NameWrapperError> {
+    if burn & !fuses::OWNER_CONTROLLED != 0 {
+        return Err(NameWrapperError::InvalidFuses);
+    }
+    if burn == 0 {
+        return Ok(());
+    }
+    let all = current | burn;
+    if all & fuses::PARENT_CANNOT_CONTROL == 0 {
+        return Err(NameWrapperError::ParentCanControl);
+    }
+    if all & fuses::CANNOT_UNWRAP == 0 {
+        return Err(NameWrapperError::CanUnwrap);
+    }
+    Ok(())
+}
+
+/// Send an encoded call to `program` and decode its reply.
+/// Returns `None` if the message could not be sent or the program rejected the call.
+async fn call<A: ActionIo>(program: ActorId, request: Vec<u8>) -> Option<A::Reply> {
+    let bytes_reply = msg::send_bytes_for_reply(program, request, 0, 0)
+        .ok()?
+        .await
+        .ok()?;
+    A::decode_reply(bytes_reply).ok()
+}
+
+async fn set_registry_owner(registry: ActorId, node: Node, owner: ActorId) -> Result<(), NameWrapperError> {
+    let request = registry_io::SetOwner::encode_call(node, owner);
+    match call::<registry_io::SetOwner>(registry, request).await {
+        Some(Ok(_)) => Ok(()),
+        _ => Err(NameWrapperError::RegistryCallFailed),
+    }
+}
+
+async fn transfer_token(registrar: ActorId, token_id: U256, to: ActorId) -> Result<(), NameWrapperError> {
+    let request = registrar_io::Transfer::encode_call(to, token_id);
+    match call::<registrar_io::Transfer>(registrar, request).await {
+        Some(Ok(_)) => Ok(()),
+        _ => Err(NameWrapperError::RegistrarCallFailed),
+    }
+}
+
+/// Error for a registry call on a wrapped `node` that failed. The wrapper is an ordinary
+/// owner in the registry, so calls fail once the node expired there or was registered
+/// again by someone else: in that case its stale entry is dropped, as `sync_expiry` does,
+/// and `NotWrapped` is returned.
+async fn registry_call_failed(registry: ActorId, node: Node) -> NameWrapperError {
+    let request = registry_io::OwnerOf::encode_call(node);
+    match call::<registry_io::OwnerOf>(registry, request).await {
+        Some(owner) if owner != Some(exec::program_id()) => {
+            NameWrapperState::state_mut().drop_name(&node);
+            NameWrapperError::NotWrapped
+        }
+        _ => NameWrapperError::RegistryCallFailed,
+    }
+}
+
+#[derive(Default)]
+pub struct Service;
+
+impl Service {
+    pub fn seed(init: InitNameWrapper) {
+        unsafe {
+            NAME_WRAPPER_STATE = Some(NameWrapperState {
+                registry: init.registry,
+                registrar: init.registrar,
+                tld_node: init.tld_node,
+                hash_scheme: init.hash_scheme,
+                wrapped: HashMap::new(),
+                tokens: HashMap::new(),
+            });
+        }
+    }
+}
+
+#[sails_rs::service(events = NameWrapperEvent)]
+impl Service {
+    pub fn new() -> Self { Self }
+
+    /// Wrap the caller's node `label` under `parent`, taking over its ownership in the
+    /// registry and holding it for `owner`. The caller must have approved the wrapper as an
+    /// operator in the registry (`SetApprovalForAll`), so it can take the node over.
+    ///
+    /// A second-level name under `tld_node` is wrapped with `PARENT_CANNOT_CONTROL` burned
+    /// until its registration expires, as only the registrar controls it from above. The
+    /// caller must also hold the name's registrar token and have approved the wrapper for
+    /// it, as the wrapper keeps the token so that it cannot be used to reclaim the name.
+    /// Any other node is wrapped with an expiry of 0, so fuses have no effect on it; nodes
+    /// get lasting fuses by being created under a locked parent with `set_subnode_owner`.
+    pub async fn wrap(&mut self, parent: Node, label: Label, owner: ActorId) -> Result<NameWrapperEvent, NameWrapperError> {
+        let caller = msg::source();
+        let s = NameWrapperState::state_ref();
+        let (registry, registrar, tld_node) = (s.registry, s.registrar, s.tld_node);
+        let labelhash = s.hash_scheme.labelhash(&label);
+        let node = s.hash_scheme.subnode(parent, labelhash);
+
+        let request = registry_io::OwnerOf::encode_call(node);
+        let registry_owner = call::<registry_io::OwnerOf>(registry, request)
+            .await
+            .ok_or(NameWrapperError::RegistryCallFailed)?;
+        if registry_owner != Some(caller) {
+            return Err(NameWrapperError::NotOwner);
+        }
+
+        let (fuses, expiry) = if parent == tld_node {
+            let request = registrar_io::OwnerOfToken::encode_call(labelhash);
+            let token_owner = call::<registrar_io::OwnerOfToken>(registrar, request)
+                .await
+                .ok_or(NameWrapperError::RegistrarCallFailed)?;
+            if token_owner != Some(caller) {
+                return Err(NameWrapperError::NotOwner);
+            }
+            let request = registrar_io::ExpiryOf::encode_call(label);
+            let expiry = call::<registrar_io::ExpiryOf>(registrar, request)
+                .await
+                .ok_or(NameWrapperError::RegistrarCallFailed)?
+                .ok_or(NameWrapperError::NotRegistered)?;
+            transfer_token(registrar, labelhash, exec::program_id()).await?;
+            (fuses::PARENT_CANNOT_CONTROL, expiry)
+        } else {
+            (0, 0)
+        };
+
+        if let Err(error) = set_registry_owner(registry, node, exec::program_id()).await {
+            if parent == tld_node {
+                let _ = transfer_token(registrar, labelhash, caller).await;
+            }
+            return Err(error);
+        }
+        let s = NameWrapperState::state_mut();
+        s.wrapped.insert(node, WrappedName { owner, fuses, expiry });
+        if parent == tld_node {
+            s.tokens.insert(node, labelhash);
+        }
+
+        let event = NameWrapperEvent::NameWrapped { node, owner, fuses, expiry };
+        self.emit_event(event.clone()).expect("Event failed");
+        Ok(event)
+    }
+
+    /// Unwrap a name, handing its registry ownership, and the registrar token of a
+    /// second-level name, to `new_owner`. Not possible once `CANNOT_UNWRAP` is burned, nor
+    /// for a second-level name whose registration expired until it is renewed.
+    pub async fn unwrap(&mut self, node: Node, new_owner: ActorId) -> Result<NameWrapperEvent, NameWrapperError> {
+        let now = exec::block_timestamp();
+        let s = NameWrapperState::state_mut();
+        let wrapped = s.owned_by(&node, &msg::source())?;
+        check_fuse(wrapped.fuses_at(now), fuses::CANNOT_UNWRAP)?;
+
+        // Release the name before awaiting the registrar and registry so it cannot be used
+        // meanwhile.
+        let wrapped = s.wrapped.remove(&node).expect("checked above");
+        if let Some(token_id) = s.tokens.get(&node).copied() {
+            if let Err(error) = transfer_token(s.registrar, token_id, new_owner).await {
+                NameWrapperState::state_mut().wrapped.insert(node, wrapped);
+                return Err(error);
+            }
+            NameWrapperState::state_mut().tokens.remove(&node);
+        }
+        if set_registry_owner(s.registry, node, new_owner).await.is_err() {
+            NameWrapperState::state_mut().wrapped.insert(node, wrapped);
+            return Err(registry_call_failed(s.registry, node).await);
+        }
+
+        let event = NameWrapperEvent::NameUnwrapped { node, owner: new_owner };
+        self.emit_event(event.clone()).expect("Event failed");
+        Ok(event)
+    }
+
+    /// Create or replace the wrapped subdomain `label` of the caller's `parent`, owned by
+    /// `owner` with `fuses` burned until `expiry` (capped at the parent's expiry).
+    ///
+    /// New subdomains cannot be created once the parent burned `CANNOT_CREATE_SUBDOMAIN`,
+    /// and existing ones cannot be replaced once they have `PARENT_CANNOT_CONTROL` burned.
+    /// Burning `PARENT_CANNOT_CONTROL` requires the parent to have `CANNOT_UNWRAP` burned.
+    pub async fn set_subnode_owner(
+        &mut self,
+        parent: Node,
+        label: Label,
+        owner: ActorId,
+        fuses: u32,
+        expiry: u64,
+    ) -> Result<NameWrapperEvent, NameWrapperError> {
+        let now = exec::block_timestamp();
+        let s = NameWrapperState::state_mut();
+        let parent_name = s.owned_by(&parent, &msg::source())?;
+        let parent_fuses = parent_name.fuses_at(now);
+        let expiry = expiry.min(parent_name.expiry);
+
+        let labelhash = s.hash_scheme.labelhash(&label);
+        let node = s.hash_scheme.subnode(parent, labelhash);
+        match s.wrapped.get(&node) {
+            Some(child) => check_fuse(child.fuses_at(now), fuses::PARENT_CANNOT_CONTROL)?,
+            None => check_fuse(parent_fuses, fuses::CANNOT_CREATE_SUBDOMAIN)?,
+        }
+        if fuses & fuses::PARENT_CANNOT_CONTROL != 0 && parent_fuses & fuses::CANNOT_UNWRAP == 0 {
+            return Err(NameWrapperError::ParentCanUnwrap);
+        }
+        check_owner_fuses(fuses & fuses::PARENT_CANNOT_CONTROL, fuses & !fuses::PARENT_CANNOT_CONTROL)?;
+
+        // Record the child before awaiting the registry, and restore it on failure.
+        let previous = s.wrapped.insert(node, WrappedName { owner, fuses, expiry });
+        let request = registry_io::SetSubnodeOwner::encode_call(parent, labelhash, exec::program_id());
+        if !matches!(call::<registry_io::SetSubnodeOwner>(s.registry, request).await, Some(Ok(_))) {
+            let s = NameWrapperState::state_mut();
+            match previous {
+                Some(previous) => s.wrapped.insert(node, previous),
+                None => s.wrapped.remove(&node),
+            };
+            return Err(registry_call_failed(s.registry, parent).await);
+        }
+
+        let event = NameWrapperEvent::NameWrapped { node, owner, fuses, expiry };
+        self.emit_event(event.clone()).expect("Event failed");
+        Ok(event)
+    }
+
+    /// Burn owner fuses on the caller's name. Requires `PARENT_CANNOT_CONTROL` to be
+    /// burned already, and `CANNOT_UNWRAP` to be burned before or together with any other
+    /// fuse. Not possible once `CANNOT_BURN_FUSES` is burned.
+    pub fn set_fuses(&mut self, node: Node, fuses: u32) -> Result<NameWrapperEvent, NameWrapperError> {
+        let now = exec::block_timestamp();
+        let s = NameWrapperState::state_mut();
+        let wrapped = s.owned_by(&node, &msg::source())?;
+        let current = wrapped.fuses_at(now);
+        check_fuse(current, fuses::CANNOT_BURN_FUSES)?;
+        check_owner_fuses(current, fuses)?;
+
+        let wrapped = s.wrapped.get_mut(&node).expect("checked above");
+        wrapped.fuses = current | fuses;
+        let (fuses, expiry) = (wrapped.fuses, wrapped.expiry);
+
+        self.emit_event(NameWrapperEvent::FusesSet { node, fuses, expiry })
+            .expect("Event failed");
+        Ok(NameWrapperEvent::FusesSet { node, fuses, expiry })
+    }
+
+    /// Burn fuses on, and extend the expiry of, a subdomain of the caller's `parent`, as
+    /// long as the subdomain has not burned `PARENT_CANNOT_CONTROL`. The expiry is capped
+    /// at the parent's and never shortened.
+    pub fn set_child_fuses(
+        &mut self,
+        parent: Node,
+        labelhash: U256,
+        fuses: u32,
+        expiry: u64,
+    ) -> Result<NameWrapperEvent, NameWrapperError> {
+        let now = exec::block_timestamp();
+        let s = NameWrapperState::state_mut();
+        let parent_name = s.owned_by(&parent, &msg::source())?;
+        let (parent_fuses, parent_expiry) = (parent_name.fuses_at(now), parent_name.expiry);
+
+        let node = s.hash_scheme.subnode(parent, labelhash);
+        let child = s.wrapped.get(&node).ok_or(NameWrapperError::NotWrapped)?;
+        let current = child.fuses_at(now);
+        check_fuse(current, fuses::PARENT_CANNOT_CONTROL)?;
+        if fuses & fuses::PARENT_CANNOT_CONTROL != 0 && parent_fuses & fuses::CANNOT_UNWRAP == 0 {
+            return Err(NameWrapperError::ParentCanUnwrap);
+        }
+        check_owner_fuses(current | (fuses & fuses::PARENT_CANNOT_CONTROL), fuses & !fuses::PARENT_CANNOT_CONTROL)?;
+
+        let child = s.wrapped.get_mut(&node).expect("checked above");
+        child.fuses = current | fuses;
+        child.expiry = child.expiry.max(expiry.min(parent_expiry));
+        let (fuses, expiry) = (child.fuses, child.expiry);
+
+        self.emit_event(NameWrapperEvent::FusesSet { node, fuses, expiry })
+            .expect("Event failed");
+        Ok(NameWrapperEvent::FusesSet { node, fuses, expiry })
+    }
+
+    /// Refresh the expiry of a wrapped second-level name from the registrar, e.g. after a
+    /// renewal. Anyone may trigger a refresh.
+    ///
+    /// A name that expired and was registered again by someone else is no longer held by
+    /// the wrapper in the registry; its stale entry is dropped and `NotWrapped` returned.
+    pub async fn sync_expiry(&mut self, label: Label) -> Result<NameWrapperEvent, NameWrapperError> {
+        let s = NameWrapperState::state_ref();
+        let node = s.hash_scheme.subnode(s.tld_node, s.hash_scheme.labelhash(&label));
+        if !s.wrapped.contains_key(&node) {
+            return Err(NameWrapperError::NotWrapped);
+        }
+
+        let request = registry_io::OwnerOf::encode_call(node);
+        let registry_owner = call::<registry_io::OwnerOf>(s.registry, request)
+            .await
+            .ok_or(NameWrapperError::RegistryCallFailed)?;
+        if registry_owner != Some(exec::program_id()) {
+            NameWrapperState::state_mut().drop_name(&node);
+            return Err(NameWrapperError::NotWrapped);
+        }
+
+        let request = registrar_io::ExpiryOf::encode_call(label);
+        let expiry = call::<registrar_io::ExpiryOf>(s.registrar, request)
+            .await
+            .ok_or(NameWrapperError::RegistrarCallFailed)?
+            .ok_or(NameWrapperError::NotRegistered)?;
+
+        // Re-borrow: the name may have been unwrapped while waiting for the reply.
+        let wrapped = NameWrapperState::state_mut()
+            .wrapped
+            .get_mut(&node)
+            .ok_or(NameWrapperError::NotWrapped)?;
+        wrapped.expiry = expiry;
+        let fuses = wrapped.fuses;
+
+        self.emit_event(NameWrapperEvent::FusesSet { node, fuses, expiry })
+            .expect("Event failed");
+        Ok(NameWrapperEvent::FusesSet { node, fuses, expiry })
+    }
+
+    /// Transfer the caller's wrapped name to `to`. Fuses stay with the name.
+    pub fn transfer(&mut self, node: Node, to: ActorId) -> Result<NameWrapperEvent, NameWrapperError> {
+        let from = msg::source();
+        let s = NameWrapperState::state_mut();
+        s.owned_by(&node, &from)?;
+        s.wrapped.get_mut(&node).expect("checked above").owner = to;
+
+        self.emit_event(NameWrapperEvent::Transfer { node, from, to })
+            .expect("Event failed");
+        Ok(NameWrapperEvent::Transfer { node, from, to })
+    }
+
+    /// Set the resolver of the caller's wrapped name in the registry. Not possible once
+    /// `CANNOT_SET_RESOLVER` is burned.
+    pub async fn set_resolver(&mut self, node: Node, resolver: ActorId) -> Result<NameWrapperEvent, NameWrapperError> {
+        let now = exec::block_timestamp();
+        let s = NameWrapperState::state_ref();
+        check_fuse(s.owned_by(&node, &msg::source())?.fuses_at(now), fuses::CANNOT_SET_RESOLVER)?;
+
+        let request = registry_io::SetResolver::encode_call(node, resolver);
+        if !matches!(call::<registry_io::SetResolver>(s.registry, request).await, Some(Ok(_))) {
+            return Err(registry_call_failed(s.registry, node).await);
+        }
+
+        self.emit_event(NameWrapperEvent::ResolverSet { node, resolver })
+            .expect("Event failed");
+        Ok(NameWrapperEvent::ResolverSet { node, resolver })
+    }
+
+    /// QUERY: Owner of a wrapped name
+    pub fn owner_of(&self, node: Node) -> Option<ActorId> {
+        NameWrapperState::state_ref().wrapped.get(&node).map(|w| w.owner)
+    }
+
+    /// QUERY: A wrapped name with the fuses currently in effect
+    pub fn get_data(&self, node: Node) -> Option<WrappedName> {
+        let now = exec::block_timestamp();
+        NameWrapperState::state_ref().wrapped.get(&node).map(|w| WrappedName {
+            fuses: w.fuses_at(now),
+            ..w.clone()
+        })
+    }
+
+    /// QUERY: Whether all of `fuses` are currently burned on a wrapped name
+    pub fn all_fuses_burned(&self, node: Node, fuses: u32) -> bool {
+        let now = exec::block_timestamp();
+        NameWrapperState::state_ref()
+            .wrapped
+            .get(&node)
+            .is_some_and(|w| w.fuses_at(now) & fuses == fuses)
+    }
+}
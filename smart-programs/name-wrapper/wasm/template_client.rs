@@ -0,0 +1,522 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    /// Construct a new NameWrapper program. It needs no rights in the registry: owners
+    /// approve it as an operator to wrap their names.
+    fn new(&self, init: InitNameWrapper) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), init)
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(init: super::InitNameWrapper) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&init)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = super::InitNameWrapper;
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Burn fuses on, and extend the expiry of, a subdomain of the caller's `parent`, as
+    /// long as the subdomain has not burned `PARENT_CANNOT_CONTROL`. The expiry is capped
+    /// at the parent's and never shortened.
+    fn set_child_fuses(
+        &mut self,
+        parent: U256,
+        labelhash: U256,
+        fuses: u32,
+        expiry: u64,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetChildFuses>::new(
+            self.remoting.clone(),
+            (parent, labelhash, fuses, expiry),
+        )
+    }
+    /// Burn owner fuses on the caller's name. Requires `PARENT_CANNOT_CONTROL` to be
+    /// burned already, and `CANNOT_UNWRAP` to be burned before or together with any other
+    /// fuse. Not possible once `CANNOT_BURN_FUSES` is burned.
+    fn set_fuses(
+        &mut self,
+        node: U256,
+        fuses: u32,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetFuses>::new(self.remoting.clone(), (node, fuses))
+    }
+    /// Set the resolver of the caller's wrapped name in the registry. Not possible once
+    /// `CANNOT_SET_RESOLVER` is burned.
+    fn set_resolver(
+        &mut self,
+        node: U256,
+        resolver: ActorId,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetResolver>::new(self.remoting.clone(), (node, resolver))
+    }
+    /// Create or replace the wrapped subdomain `label` of the caller's `parent`, owned by
+    /// `owner` with `fuses` burned until `expiry` (capped at the parent's expiry).
+    ///
+    /// New subdomains cannot be created once the parent burned `CANNOT_CREATE_SUBDOMAIN`,
+    /// and existing ones cannot be replaced once they have `PARENT_CANNOT_CONTROL` burned.
+    /// Burning `PARENT_CANNOT_CONTROL` requires the parent to have `CANNOT_UNWRAP` burned.
+    fn set_subnode_owner(
+        &mut self,
+        parent: U256,
+        label: Vec<u8>,
+        owner: ActorId,
+        fuses: u32,
+        expiry: u64,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetSubnodeOwner>::new(
+            self.remoting.clone(),
+            (parent, label, owner, fuses, expiry),
+        )
+    }
+    /// Refresh the expiry of a wrapped second-level name from the registrar, e.g. after a
+    /// renewal. Anyone may trigger a refresh.
+    ///
+    /// A name that expired and was registered again by someone else is no longer held by
+    /// the wrapper in the registry; its stale entry is dropped and `NotWrapped` returned.
+    fn sync_expiry(
+        &mut self,
+        label: Vec<u8>,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SyncExpiry>::new(self.remoting.clone(), label)
+    }
+    /// Transfer the caller's wrapped name to `to`. Fuses stay with the name.
+    fn transfer(
+        &mut self,
+        node: U256,
+        to: ActorId,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Transfer>::new(self.remoting.clone(), (node, to))
+    }
+    /// Unwrap a name, handing its registry ownership, and the registrar token of a
+    /// second-level name, to `new_owner`. Not possible once `CANNOT_UNWRAP` is burned, nor
+    /// for a second-level name whose registration expired until it is renewed.
+    fn unwrap(
+        &mut self,
+        node: U256,
+        new_owner: ActorId,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Unwrap>::new(self.remoting.clone(), (node, new_owner))
+    }
+    /// Wrap the caller's node `label` under `parent`, taking over its ownership in the
+    /// registry and holding it for `owner`. The caller must have approved the wrapper as an
+    /// operator in the registry (`SetApprovalForAll`), so it can take the node over.
+    ///
+    /// A second-level name under `tld_node` is wrapped with `PARENT_CANNOT_CONTROL` burned
+    /// until its registration expires, as only the registrar controls it from above. The
+    /// caller must also hold the name's registrar token and have approved the wrapper for
+    /// it, as the wrapper keeps the token so that it cannot be used to reclaim the name.
+    /// Any other node is wrapped with an expiry of 0, so fuses have no effect on it; nodes
+    /// get lasting fuses by being created under a locked parent with `set_subnode_owner`.
+    fn wrap(
+        &mut self,
+        parent: U256,
+        label: Vec<u8>,
+        owner: ActorId,
+    ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Wrap>::new(self.remoting.clone(), (parent, label, owner))
+    }
+    /// QUERY: Whether all of `fuses` are currently burned on a wrapped name
+    fn all_fuses_burned(
+        &self,
+        node: U256,
+        fuses: u32,
+    ) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, service::io::AllFusesBurned>::new(self.remoting.clone(), (node, fuses))
+    }
+    /// QUERY: A wrapped name with the fuses currently in effect
+    fn get_data(&self, node: U256) -> impl Query<Output = Option<WrappedName>, Args = R::Args> {
+        RemotingAction::<_, service::io::GetData>::new(self.remoting.clone(), node)
+    }
+    /// QUERY: Owner of a wrapped name
+    fn owner_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::OwnerOf>::new(self.remoting.clone(), node)
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct SetChildFuses(());
+        impl SetChildFuses {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, labelhash: U256, fuses: u32, expiry: u64) -> Vec<u8> {
+                <SetChildFuses as ActionIo>::encode_call(&(parent, labelhash, fuses, expiry))
+            }
+        }
+        impl ActionIo for SetChildFuses {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 83, 101, 116, 67, 104, 105, 108, 100, 70,
+                117, 115, 101, 115,
+            ];
+            type Params = (U256, U256, u32, u64);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct SetFuses(());
+        impl SetFuses {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, fuses: u32) -> Vec<u8> {
+                <SetFuses as ActionIo>::encode_call(&(node, fuses))
+            }
+        }
+        impl ActionIo for SetFuses {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101, 116, 70, 117, 115, 101, 115,
+            ];
+            type Params = (U256, u32);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct SetResolver(());
+        impl SetResolver {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, resolver: ActorId) -> Vec<u8> {
+                <SetResolver as ActionIo>::encode_call(&(node, resolver))
+            }
+        }
+        impl ActionIo for SetResolver {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 83, 101, 116, 82, 101, 115, 111, 108, 118,
+                101, 114,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct SetSubnodeOwner(());
+        impl SetSubnodeOwner {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                parent: U256,
+                label: Vec<u8>,
+                owner: ActorId,
+                fuses: u32,
+                expiry: u64,
+            ) -> Vec<u8> {
+                <SetSubnodeOwner as ActionIo>::encode_call(&(parent, label, owner, fuses, expiry))
+            }
+        }
+        impl ActionIo for SetSubnodeOwner {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 60, 83, 101, 116, 83, 117, 98, 110, 111, 100,
+                101, 79, 119, 110, 101, 114,
+            ];
+            type Params = (U256, Vec<u8>, ActorId, u32, u64);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct SyncExpiry(());
+        impl SyncExpiry {
+            #[allow(dead_code)]
+            pub fn encode_call(label: Vec<u8>) -> Vec<u8> {
+                <SyncExpiry as ActionIo>::encode_call(&label)
+            }
+        }
+        impl ActionIo for SyncExpiry {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 83, 121, 110, 99, 69, 120, 112, 105, 114,
+                121,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct Transfer(());
+        impl Transfer {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, to: ActorId) -> Vec<u8> {
+                <Transfer as ActionIo>::encode_call(&(node, to))
+            }
+        }
+        impl ActionIo for Transfer {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct Unwrap(());
+        impl Unwrap {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, new_owner: ActorId) -> Vec<u8> {
+                <Unwrap as ActionIo>::encode_call(&(node, new_owner))
+            }
+        }
+        impl ActionIo for Unwrap {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 85, 110, 119, 114, 97, 112,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct Wrap(());
+        impl Wrap {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, label: Vec<u8>, owner: ActorId) -> Vec<u8> {
+                <Wrap as ActionIo>::encode_call(&(parent, label, owner))
+            }
+        }
+        impl ActionIo for Wrap {
+            const ROUTE: &'static [u8] =
+                &[28, 83, 101, 114, 118, 105, 99, 101, 16, 87, 114, 97, 112];
+            type Params = (U256, Vec<u8>, ActorId);
+            type Reply = Result<super::NameWrapperEvent, super::NameWrapperError>;
+        }
+        pub struct AllFusesBurned(());
+        impl AllFusesBurned {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, fuses: u32) -> Vec<u8> {
+                <AllFusesBurned as ActionIo>::encode_call(&(node, fuses))
+            }
+        }
+        impl ActionIo for AllFusesBurned {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 65, 108, 108, 70, 117, 115, 101, 115, 66,
+                117, 114, 110, 101, 100,
+            ];
+            type Params = (U256, u32);
+            type Reply = bool;
+        }
+        pub struct GetData(());
+        impl GetData {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <GetData as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for GetData {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 71, 101, 116, 68, 97, 116, 97,
+            ];
+            type Params = U256;
+            type Reply = Option<super::WrappedName>;
+        }
+        pub struct OwnerOf(());
+        impl OwnerOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <OwnerOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for OwnerOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 79, 119, 110, 101, 114, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ServiceEvents {
+            NameWrapped {
+                node: U256,
+                owner: ActorId,
+                fuses: u32,
+                expiry: u64,
+            },
+            NameUnwrapped {
+                node: U256,
+                owner: ActorId,
+            },
+            FusesSet {
+                node: U256,
+                fuses: u32,
+                expiry: u64,
+            },
+            Transfer {
+                node: U256,
+                from: ActorId,
+                to: ActorId,
+            },
+            ResolverSet {
+                node: U256,
+                resolver: ActorId,
+            },
+        }
+        impl EventIo for ServiceEvents {
+            const ROUTE: &'static [u8] = &[28, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[44, 78, 97, 109, 101, 87, 114, 97, 112, 112, 101, 100],
+                &[
+                    52, 78, 97, 109, 101, 85, 110, 119, 114, 97, 112, 112, 101, 100,
+                ],
+                &[32, 70, 117, 115, 101, 115, 83, 101, 116],
+                &[32, 84, 114, 97, 110, 115, 102, 101, 114],
+                &[44, 82, 101, 115, 111, 108, 118, 101, 114, 83, 101, 116],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<ServiceEvents> {
+            RemotingListener::<_, ServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitNameWrapper {
+    pub registry: ActorId,
+    /// Registrar of `tld_node`, asked for the expiry of wrapped second-level names
+    pub registrar: ActorId,
+    pub tld_node: U256,
+    /// Must match the registry's `hash_scheme`, as labels are hashed here.
+    pub hash_scheme: HashScheme,
+}
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum NameWrapperError {
+    NotWrapped,
+    NotOwner,
+    /// The operation is prohibited by a burned fuse
+    FuseBurned {
+        fuse: u32,
+    },
+    /// Owner fuses require `PARENT_CANNOT_CONTROL` to be burned first
+    ParentCanControl,
+    /// Other owner fuses require `CANNOT_UNWRAP` to be burned first
+    CanUnwrap,
+    /// `PARENT_CANNOT_CONTROL` requires the parent to have burned `CANNOT_UNWRAP`
+    ParentCanUnwrap,
+    /// The fuses cannot be burned by this caller
+    InvalidFuses,
+    NotRegistered,
+    RegistryCallFailed,
+    RegistrarCallFailed,
+}
+/// A name held by the wrapper on behalf of its owner
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct WrappedName {
+    pub owner: ActorId,
+    pub fuses: u32,
+    /// Fuses only hold until this timestamp; afterwards the name reads as having none.
+    pub expiry: u64,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, init: InitNameWrapper) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn set_child_fuses(
+            &mut self,
+            parent: U256,
+            labelhash: U256,
+            fuses: u32,
+            expiry: u64,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn set_fuses(
+            &mut self,
+            node: U256,
+            fuses: u32,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn set_resolver(
+            &mut self,
+            node: U256,
+            resolver: ActorId,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn set_subnode_owner(
+            &mut self,
+            parent: U256,
+            label: Vec<u8>,
+            owner: ActorId,
+            fuses: u32,
+            expiry: u64,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn sync_expiry(
+            &mut self,
+            label: Vec<u8>,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn transfer(
+            &mut self,
+            node: U256,
+            to: ActorId,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn unwrap(
+            &mut self,
+            node: U256,
+            new_owner: ActorId,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn wrap(
+            &mut self,
+            parent: U256,
+            label: Vec<u8>,
+            owner: ActorId,
+        ) -> impl Call<Output = Result<NameWrapperEvent, NameWrapperError>, Args = Self::Args>;
+        fn all_fuses_burned(
+            &self,
+            node: U256,
+            fuses: u32,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn get_data(
+            &self,
+            node: U256,
+        ) -> impl Query<Output = Option<WrappedName>, Args = Self::Args>;
+        fn owner_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+    }
+}
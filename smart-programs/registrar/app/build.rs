@@ -0,0 +1,20 @@
+use sails_client_gen::ClientGenerator;
+use std::{env, path::PathBuf};
+
+fn main() {
+    // Path where the file "Cargo.toml" is located (points to the app crate)
+    let cargo_toml_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    // Clients are generated into 'OUT_DIR' and included from src/clients.rs
+    let outdir_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // IDLs of the registry and resolver programs, limited to the calls the registrar makes
+    for program in ["registry", "resolver"] {
+        let idl_path = cargo_toml_path.join(format!("idls/{program}.idl"));
+        println!("cargo:rerun-if-changed={}", idl_path.display());
+
+        ClientGenerator::from_idl_path(&idl_path)
+            .generate_to(outdir_path.join(format!("{program}_client.rs")))
+            .unwrap();
+    }
+}
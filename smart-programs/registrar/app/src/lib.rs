@@ -1,6 +1,7 @@
 
 #![no_std]
 use sails_rs::prelude::*;
+pub mod clients;
 pub mod services;
 use services::service::{Service, InitRegistrar};
 
@@ -13,7 +14,7 @@ impl Program {
         Self
     }
 
-    #[route("Service")]
+    #[export(route = "Service")]
     pub fn service(&self) -> Service {
         Service::new()
     }
@@ -1,22 +1,51 @@
-
-#![no_std]
 #![allow(static_mut_refs)]
 
 use sails_rs::{
     prelude::*,
-    gstd::msg,
-    collections::{HashMap},
+    gstd::{msg, exec},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
 };
 use sails_rs::calls::ActionIo;
 
-use crate::services::utils::*;
+use crate::clients::registry_client::{service::io as registry_io, RegistryEvent};
+use crate::clients::resolver_client::service::io as resolver_io;
+use vns_common::{blake2_256, commitment, confusables, pagination};
+use vns_common::normalize::{normalize_label, LabelError};
+
+pub use vns_common::{HashScheme, Label, Node};
+
+/// Id of the token representing a registered label: its labelhash under the registry's
+/// hash scheme, so it also identifies the label's node under `tld_node`.
+pub type TokenId = U256;
 
-pub type Node = U256;
-pub type Label = Vec<u8>;
+/// Hash committed to before registering a name, see `make_commitment`.
+pub type Commitment = [u8; 32];
 
 const MAX_LABEL_LENGTH: usize = 256; 
 const MAX_LABELS_RESERVED: usize = 100; 
 const MAX_COMMITMENTS: usize = 1000; 
+const MAX_SEALED_BIDS: usize = 1000;
+const MAX_PAYEES: usize = 10;
+/// Payee shares are expressed in basis points of the kept revenue.
+const TOTAL_SHARES: u16 = 10_000;
+/// Prices are set per year of registration and charged pro rata.
+const YEAR_MS: u64 = 365 * 24 * 60 * 60 * 1000;
+/// Number of price tiers: labels of 1, 2, 3, 4, and 5 or more characters. Labels shorter
+/// than `normalize::MIN_LABEL_CHARS` (3) cannot be registered, so the 1- and 2-character
+/// tiers are reserved: they only take effect if that minimum is lowered.
+pub const PRICE_TIERS: usize = 5;
+/// Multi-year discounts are expressed in basis points of the price.
+const MAX_DISCOUNT: u16 = 10_000;
+/// Registration granted to the winner of an auction.
+const AUCTION_DURATION: u64 = YEAR_MS;
+const DEFAULT_BIDDING_PERIOD: u64 = 3 * 24 * 60 * 60 * 1000;
+const DEFAULT_REVEAL_PERIOD: u64 = 2 * 24 * 60 * 60 * 1000;
+/// `2^(-i/16)` for `i` in `0..16`, scaled by `2^32`: the decay of the expiry premium within
+/// a half-life, in sixteenths.
+const HALVING_FRACTIONS: [u128; 16] = [
+    4294967296, 4112874773, 3938502375, 3771522796, 3611622602, 3458501653, 3311872529, 3171459999,
+    3037000499, 2908241642, 2784941737, 2666869344, 2553802833, 2445529971, 2341847523, 2242560871,
+];
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 #[codec(crate = gstd::codec)]
@@ -25,45 +54,245 @@ pub enum RegistrarEvent {
     CommitSubmitted { commitment: [u8; 32], timestamp: u64 },
     NameRegistered { name: Label, owner: ActorId, expires: u64, cost: u128 },
     NameRenewed { name: Label, expires: u64, cost: u128 },
-    PricesSet { base: u128, premium: u128 },
+    PriceTiersSet { tiers: PriceTiers },
     CommitAgesSet { min: u64, max: u64 },
     GracePeriodSet { grace: u64 },
     NamesReserved { labels: Vec<Label> },
     Withdrawn { to: ActorId, amount: u128 },
+    PayeesSet { payees: Vec<(ActorId, u16)> },
+    Transfer { from: ActorId, to: ActorId, token_id: TokenId },
+    Approval { owner: ActorId, approved: ActorId, token_id: TokenId },
+    ApprovalForAll { owner: ActorId, operator: ActorId, approved: bool },
+    NameReclaimed { token_id: TokenId, owner: ActorId },
+    ConfusableAllowed { name: Label, allowed: bool },
+    ExpiryPremiumSet { premium: ExpiryPremium },
+    AuctionStarted { labelhash: U256, reveal_at: u64, ends_at: u64 },
+    BidSealed { hash: [u8; 32], bidder: ActorId, deposit: u128 },
+    BidRevealed { name: Label, bidder: ActorId, value: u128, accepted: bool },
+    AuctionFinalized { name: Label, winner: Option<ActorId>, price: u128 },
+    AuctionPeriodsSet { bidding: u64, reveal: u64 },
+    BidReclaimed { hash: [u8; 32], bidder: ActorId, deposit: u128 },
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RegistrarError {
+    NotController,
+    NameTooLong,
+    NameReserved,
+    NameNotAvailable,
+    NameNotRenewable,
+    AddrRequiresResolver,
+    CommitmentAlreadyExists,
+    TooManyCommitments,
+    NoValidCommitment,
+    CommitmentTooNew,
+    CommitmentExpired,
+    TooManyLabels,
+    Overflow,
+    RegistryCallFailed,
+    InsufficientPayment { price: u128, paid: u128 },
+    InsufficientBalance { requested: u128, available: u128 },
+    TooManyPayees,
+    InvalidShares { total: u32 },
+    NothingToRelease,
+    BelowExistentialDeposit { amount: u128, existential_deposit: u128 },
+    InsufficientProgramBalance { requested: u128, available: u128 },
+    TransferFailed,
+    TokenNotFound,
+    NotTokenOwnerOrApproved,
+    TransferToZeroAddress,
+    InvalidLabel(LabelError),
+    ConfusableName { with: Label },
+    InvalidDiscount { discount: u16 },
+    NameInAuction,
+    AuctionAlreadyStarted,
+    AuctionNotFound,
+    NotInRevealPeriod,
+    AuctionNotEnded,
+    NoValidBid,
+    DepositTooLow { minimum: u128 },
+    TooManySealedBids,
+    BidNotReclaimable,
+}
+
+/// Admin-set pricing table
+#[derive(Debug, Clone, Default, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PriceTiers {
+    /// Price per year of a label by its length in Unicode characters: 1, 2, 3, 4, and 5
+    /// or more. The last tier is the base price; shorter labels pay the difference as a
+    /// premium.
+    pub per_year: [u128; PRICE_TIERS],
+    /// Discount, in basis points, on registrations and renewals of two years or more
+    pub multi_year_discount: u16,
+}
+
+/// Temporary premium on names that just became available again after expiring, so that
+/// they are not released to whoever registers first at the normal price. It starts at
+/// `start` once the grace period ends, halves every `half_life` and reaches zero after
+/// `window`. Disabled while `start` or `half_life` is zero.
+#[derive(Debug, Clone, Copy, Default, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ExpiryPremium {
+    pub start: u128,
+    pub half_life: u64,
+    pub window: u64,
+}
+
+impl ExpiryPremium {
+    /// Premium `elapsed` ms after the end of a name's grace period. The value the decay
+    /// would have at the end of the window is subtracted, so the premium ends at zero
+    /// rather than dropping to it.
+    pub fn at(&self, elapsed: u64) -> u128 {
+        if self.half_life == 0 || elapsed >= self.window {
+            return 0;
+        }
+        self.decayed(elapsed).saturating_sub(self.decayed(self.window))
+    }
+
+    /// `start * 2^(-elapsed / half_life)`, in steps of a sixteenth of a half-life
+    fn decayed(&self, elapsed: u64) -> u128 {
+        let halvings = elapsed / self.half_life;
+        if halvings >= u128::BITS as u64 {
+            return 0;
+        }
+        let sixteenths = (elapsed % self.half_life) as u128 * 16 / self.half_life as u128;
+        let fraction = HALVING_FRACTIONS[sixteenths as usize];
+        let halved = self.start >> halvings;
+        // Split so that the product cannot overflow.
+        (halved >> 32) * fraction + (((halved & 0xFFFF_FFFF) * fraction) >> 32)
+    }
+}
+
+/// Price of a registration or renewal: `base + premium - discount + expiry_premium`
+#[derive(Debug, Clone, Copy, Default, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PriceBreakdown {
+    /// Base tier price for the duration
+    pub base: u128,
+    /// Surcharge of the label's tier over the base tier
+    pub premium: u128,
+    /// Multi-year discount on `base + premium`
+    pub discount: u128,
+    /// Decaying premium of a recently expired name, see `ExpiryPremium`
+    pub expiry_premium: u128,
+}
+
+impl PriceBreakdown {
+    pub fn total(&self) -> u128 {
+        self.base
+            .saturating_add(self.premium)
+            .saturating_sub(self.discount)
+            .saturating_add(self.expiry_premium)
+    }
+}
+
+/// Sealed-bid (Vickrey) auction of a label, known only by its labelhash until bids are
+/// revealed. The highest bidder wins and pays the second-highest bid.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Auction {
+    /// End of bidding: bids sealed later are refused at reveal
+    pub reveal_at: u64,
+    /// End of the reveal period, after which the auction can be finalized
+    pub ends_at: u64,
+    /// Highest valid bid revealed so far: bidder, value and deposit
+    pub highest: Option<(ActorId, u128, u128)>,
+    /// Second-highest valid bid revealed so far
+    pub second: u128,
+}
+
+/// Bid sealed by `bidder` at `sealed_at`, not revealed yet
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SealedBid {
+    pub bidder: ActorId,
+    pub deposit: u128,
+    pub sealed_at: u64,
+}
+
+/// A registered label held as a non-fungible token
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct NameToken {
+    pub name: Label,
+    pub owner: ActorId,
 }
 
 #[derive(Debug, Default)]
 pub struct RegistrarState {
     pub registry: ActorId,
     pub tld_node: Node,
-    pub commits: HashMap<[u8; 32], u64>,
-    pub expires: HashMap<Label, u64>,
+    pub hash_scheme: HashScheme,
+    pub commits: BTreeMap<Commitment, u64>,
+    pub expires: BTreeMap<Label, u64>,
     pub reserved: Vec<Label>,
-    pub base_price: u128,
-    pub premium_price: u128,
+    pub price_tiers: PriceTiers,
+    pub expiry_premium: ExpiryPremium,
     pub min_commit_age: u64,
     pub max_commit_age: u64,
     pub grace_period: u64,
     pub controller: ActorId,
+    /// Revenue not assigned to any payee, withdrawable by the admin.
     pub balance: u128,
+    /// Revenue split: payee and its share in basis points.
+    pub payees: Vec<(ActorId, u16)>,
+    /// Revenue assigned to each payee and not yet released.
+    pub payee_balances: HashMap<ActorId, u128>,
+    pub tokens: HashMap<TokenId, NameToken>,
+    /// Number of tokens held by each account, expired ones included.
+    pub token_balances: HashMap<ActorId, u64>,
+    pub token_approvals: HashMap<TokenId, ActorId>,
+    /// (owner, operator) pairs where the operator may manage all of the owner's tokens.
+    pub operator_approvals: HashSet<(ActorId, ActorId)>,
+    /// Names whose tokens each account holds, kept in step with `tokens`.
+    pub names_by_owner: BTreeMap<ActorId, BTreeSet<Label>>,
+    /// Registered and reserved names by confusable skeleton. Expired names are kept and
+    /// skipped by `confusable_with`.
+    pub skeletons: HashMap<Label, BTreeSet<Label>>,
+    /// Names that may be registered even though they are confusable with another name.
+    pub confusable_allowlist: HashSet<Label>,
+    /// Auctions in progress, by labelhash
+    pub auctions: HashMap<U256, Auction>,
+    /// Sealed bids not revealed yet, by bid hash. Kept apart from `commits` so bids cannot
+    /// crowd out registrations.
+    pub sealed_bids: HashMap<[u8; 32], SealedBid>,
+    pub bidding_period: u64,
+    pub reveal_period: u64,
 }
 
+/// Registrar settings with one page of registered names; commitments are paged separately
+/// by `commits_page`.
 #[derive(Debug, Encode, Decode, TypeInfo, Clone)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct IoRegistrarState {
     pub registry: ActorId,
     pub tld_node: Node,
-    pub commits: Vec<([u8; 32], u64)>,
+    pub hash_scheme: HashScheme,
+    /// Registered names and their expiries, in name order
     pub expires: Vec<(Label, u64)>,
+    /// Cursor of the next page of `expires`, if there are more
+    pub next_cursor: Option<Label>,
     pub reserved: Vec<Label>,
-    pub base_price: u128,
-    pub premium_price: u128,
+    pub price_tiers: PriceTiers,
+    pub expiry_premium: ExpiryPremium,
     pub min_commit_age: u64,
     pub max_commit_age: u64,
     pub grace_period: u64,
     pub controller: ActorId,
     pub balance: u128,
+    pub payees: Vec<(ActorId, u16)>,
+    pub bidding_period: u64,
+    pub reveal_period: u64,
 }
 
 #[derive(Debug, Encode, Decode, Clone, TypeInfo)]
@@ -72,9 +301,10 @@ pub struct IoRegistrarState {
 pub struct InitRegistrar {
     pub registry: ActorId,
     pub tld_node: Node,
+    /// Must match the registry's `hash_scheme`, as labels are hashed by the registrar.
+    pub hash_scheme: HashScheme,
     pub controller: ActorId,
-    pub base_price: u128,
-    pub premium_price: u128,
+    pub price_tiers: PriceTiers,
     pub min_commit_age: u64,
     pub max_commit_age: u64,
     pub grace_period: u64,
@@ -99,27 +329,124 @@ impl RegistrarState {
     pub fn is_admin(&self, actor: &ActorId) -> bool {
         &self.controller == actor
     }
+    /// Book revenue held by the program: each payee gets its share, and whatever is left
+    /// (unassigned shares and rounding) goes to the admin-withdrawable balance.
+    pub fn credit_revenue(&mut self, amount: u128) {
+        let mut assigned: u128 = 0;
+        for (payee, share) in &self.payees {
+            let cut = amount.saturating_mul(*share as u128) / TOTAL_SHARES as u128;
+            let owed = self.payee_balances.entry(*payee).or_default();
+            *owed = owed.saturating_add(cut);
+            assigned = assigned.saturating_add(cut);
+        }
+        self.balance = self.balance.saturating_add(amount.saturating_sub(assigned));
+    }
+    /// Owner of a token whose name has not expired. Expired tokens keep their entry until
+    /// the name is registered again, but cannot be used.
+    pub fn live_token_owner(&self, token_id: &TokenId, now: u64) -> Option<ActorId> {
+        let token = self.tokens.get(token_id)?;
+        let expires_at = self.expires.get(&token.name).copied().unwrap_or(0);
+        (now <= expires_at).then_some(token.owner)
+    }
+    /// Owner of a live token, provided `spender` is that owner, approved for the token,
+    /// or an operator of the owner.
+    pub fn check_approved_or_owner(&self, spender: &ActorId, token_id: &TokenId, now: u64) -> Result<ActorId, RegistrarError> {
+        let owner = self.live_token_owner(token_id, now).ok_or(RegistrarError::TokenNotFound)?;
+        if &owner == spender
+            || self.token_approvals.get(token_id) == Some(spender)
+            || self.operator_approvals.contains(&(owner, *spender))
+        {
+            Ok(owner)
+        } else {
+            Err(RegistrarError::NotTokenOwnerOrApproved)
+        }
+    }
+    /// Whether `name` is reserved, in auction, or registered and not past its grace period.
+    pub fn is_taken(&self, name: &Label, now: u64) -> bool {
+        let expires_at = self.expires.get(name).copied().unwrap_or(0);
+        self.reserved.contains(name)
+            || now <= expires_at.saturating_add(self.grace_period)
+            || self.auctions.contains_key(&self.hash_scheme.labelhash(name))
+    }
+    /// A taken name other than `name` that looks like it, unless `name` is allowlisted.
+    pub fn confusable_with(&self, name: &Label, now: u64) -> Option<&Label> {
+        if self.confusable_allowlist.contains(name) {
+            return None;
+        }
+        self.skeletons
+            .get(&confusables::skeleton(name))?
+            .iter()
+            .find(|other| *other != name && self.is_taken(other, now))
+    }
+    /// Smallest deposit a sealed bid may lock: a year of the base price, and never less than
+    /// the existential deposit so it can always be refunded.
+    pub fn min_bid_deposit(&self) -> u128 {
+        self.price_tiers.per_year[PRICE_TIERS - 1].max(exec::env_vars().existential_deposit)
+    }
+    /// Price of registering `name` for `duration` at `now`, including the premium of a
+    /// name released after expiring.
+    pub fn registration_price(&self, name: &Label, duration: u64, now: u64) -> PriceBreakdown {
+        let mut price = Service::calc_price(name, duration, &self.price_tiers);
+        if let Some(expires_at) = self.expires.get(name) {
+            let released_at = expires_at.saturating_add(self.grace_period);
+            if now > released_at {
+                price.expiry_premium = self.expiry_premium.at(now - released_at);
+            }
+        }
+        price
+    }
+    /// Give `token_id` to `to`, minting it for `name` if it does not exist yet, and clear
+    /// its approval. Returns the previous owner, or the zero address on mint.
+    pub fn move_token(&mut self, token_id: TokenId, name: &Label, to: ActorId) -> ActorId {
+        self.token_approvals.remove(&token_id);
+        let from = match self.tokens.insert(token_id, NameToken { name: name.clone(), owner: to }) {
+            Some(previous) => previous.owner,
+            None => ActorId::zero(),
+        };
+        if let Some(count) = self.token_balances.get_mut(&from) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.token_balances.remove(&from);
+            }
+        }
+        if let Some(names) = self.names_by_owner.get_mut(&from) {
+            names.remove(name);
+            if names.is_empty() {
+                self.names_by_owner.remove(&from);
+            }
+        }
+        *self.token_balances.entry(to).or_default() += 1;
+        self.names_by_owner.entry(to).or_default().insert(name.clone());
+        from
+    }
 }
 
-impl From<RegistrarState> for IoRegistrarState {
-    fn from(s: RegistrarState) -> Self {
-        Self {
-            registry: s.registry,
-            tld_node: s.tld_node,
-            commits: s.commits.iter().map(|(k, v)| (*k, *v)).collect(),
-            expires: s.expires.iter().map(|(k, v)| (k.clone(), *v)).collect(),
-            reserved: s.reserved.iter().cloned().collect(),
-            base_price: s.base_price,
-            premium_price: s.premium_price,
-            min_commit_age: s.min_commit_age,
-            max_commit_age: s.max_commit_age,
-            grace_period: s.grace_period,
-            controller: s.controller,
-            balance: s.balance,
+impl RegistrarState {
+    /// Settings with the page of names following `cursor`
+    pub fn io_state(&self, cursor: Option<&Label>, limit: u32) -> IoRegistrarState {
+        let (expires, next_cursor) = pagination::page(&self.expires, cursor, limit);
+        IoRegistrarState {
+            registry: self.registry,
+            tld_node: self.tld_node,
+            hash_scheme: self.hash_scheme,
+            expires,
+            next_cursor,
+            reserved: self.reserved.clone(),
+            price_tiers: self.price_tiers.clone(),
+            expiry_premium: self.expiry_premium,
+            min_commit_age: self.min_commit_age,
+            max_commit_age: self.max_commit_age,
+            grace_period: self.grace_period,
+            controller: self.controller,
+            balance: self.balance,
+            payees: self.payees.clone(),
+            bidding_period: self.bidding_period,
+            reveal_period: self.reveal_period,
         }
     }
 }
 
+#[derive(Default)]
 pub struct Service;
 
 impl Service {
@@ -128,42 +455,231 @@ impl Service {
             REGISTRAR_STATE = Some(RegistrarState {
                 registry: init.registry,
                 tld_node: init.tld_node,
-                commits: HashMap::new(),
-                expires: HashMap::new(),
+                hash_scheme: init.hash_scheme,
+                commits: BTreeMap::new(),
+                expires: BTreeMap::new(),
                 reserved: Vec::new(),
-                base_price: init.base_price,
-                premium_price: init.premium_price,
+                price_tiers: init.price_tiers,
+                expiry_premium: ExpiryPremium::default(),
                 min_commit_age: init.min_commit_age,
                 max_commit_age: init.max_commit_age,
                 grace_period: init.grace_period,
                 controller: init.controller,
                 balance: 0,
+                payees: Vec::new(),
+                payee_balances: HashMap::new(),
+                tokens: HashMap::new(),
+                token_balances: HashMap::new(),
+                token_approvals: HashMap::new(),
+                operator_approvals: HashSet::new(),
+                names_by_owner: BTreeMap::new(),
+                skeletons: HashMap::new(),
+                confusable_allowlist: HashSet::new(),
+                auctions: HashMap::new(),
+                sealed_bids: HashMap::new(),
+                bidding_period: DEFAULT_BIDDING_PERIOD,
+                reveal_period: DEFAULT_REVEAL_PERIOD,
             });
         }
     }
 }
 
+/// Send `amount` back to `to` and return what was actually refunded. Amounts below the
+/// existential deposit cannot be transferred, so such dust is kept by the program instead.
+fn refund(to: ActorId, amount: u128) -> u128 {
+    if amount == 0 || amount < exec::env_vars().existential_deposit {
+        return 0;
+    }
+    if msg::send_bytes(to, b"", amount).is_err() {
+        return 0;
+    }
+    amount
+}
+
+/// Keep `cost` out of the `paid` value, refund the rest to `caller` and book whatever the
+/// program ends up keeping as revenue.
+fn settle_payment(caller: ActorId, paid: u128, cost: u128) {
+    let kept = paid - refund(caller, paid.saturating_sub(cost));
+    RegistrarState::state_mut().credit_revenue(kept);
+}
+
+/// Transfer `amount` of the program's own funds to `to`. Transfers below the existential
+/// deposit would be rejected by the network; the program's own existential deposit is
+/// locked by the network and not part of the value available.
+fn transfer(to: ActorId, amount: u128) -> Result<(), RegistrarError> {
+    let existential_deposit = exec::env_vars().existential_deposit;
+    if amount < existential_deposit {
+        return Err(RegistrarError::BelowExistentialDeposit { amount, existential_deposit });
+    }
+    let available = exec::value_available();
+    if amount > available {
+        return Err(RegistrarError::InsufficientProgramBalance { requested: amount, available });
+    }
+    msg::send_bytes(to, b"", amount).map_err(|_| RegistrarError::TransferFailed)?;
+    Ok(())
+}
+
+/// Normalize a name as it is registered (see `vns_common::normalize`). The length limit
+/// applies to the normalized label.
+fn normalize(name: &[u8]) -> Result<Label, RegistrarError> {
+    let label = normalize_label(name).map_err(RegistrarError::InvalidLabel)?;
+    if label.len() > MAX_LABEL_LENGTH {
+        return Err(RegistrarError::NameTooLong);
+    }
+    Ok(label)
+}
+
+/// Commitment to registering a normalized name, in the current `COMMITMENT_VERSION` layout
+fn make_commitment(
+    name: &Label,
+    owner: ActorId,
+    secret: [u8; 32],
+    salt: [u8; 32],
+    duration: u64,
+    resolver: Option<ActorId>,
+) -> [u8; 32] {
+    let resolver = resolver.map(ActorId::into_bytes);
+    commitment::make_commitment(name, &owner.into_bytes(), &secret, &salt, duration, resolver.as_ref())
+}
+
+/// Sealed bid of `bidder` on `name`: `blake2_256(name || bidder || value || salt)`, with
+/// `value` as 16 little-endian bytes.
+fn bid_hash(name: &[u8], bidder: ActorId, value: u128, salt: [u8; 32]) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(name);
+    preimage.extend_from_slice(bidder.as_ref());
+    preimage.extend_from_slice(&value.to_le_bytes());
+    preimage.extend_from_slice(&salt);
+    blake2_256(&preimage)
+}
+
+/// Outcome of a successful `claim`
+struct Claimed {
+    name: Label,
+    expires: u64,
+    price: u128,
+    /// Previous holder of the name's token, or the zero address if it was just minted
+    from: ActorId,
+    token_id: TokenId,
+}
+
+/// Send an encoded call to `program` and decode its reply.
+/// Returns `None` if the message could not be sent or the program rejected the call.
+async fn call<A: ActionIo>(program: ActorId, request: Vec<u8>) -> Option<A::Reply> {
+    let bytes_reply = msg::send_bytes_for_reply(program, request, 0, 0)
+        .ok()?
+        .await
+        .ok()?;
+    A::decode_reply(bytes_reply).ok()
+}
+
+/// Ask the registry to assign `label` under `parent` to `owner` until `expiry`, returning
+/// the new subnode.
+async fn mint_subnode(registry: ActorId, parent: Node, label: U256, owner: ActorId, expiry: u64) -> Option<Node> {
+    let request = registry_io::SetSubnodeOwnerWithExpiry::encode_call(parent, label, owner, expiry);
+    match call::<registry_io::SetSubnodeOwnerWithExpiry>(registry, request).await? {
+        Ok(RegistryEvent::NewSubnodeOwner { subnode, .. }) => Some(subnode),
+        _ => None,
+    }
+}
+
+/// Mint `label` under `parent` with `resolver` (and optionally an `addr` record) configured
+/// before handing the subnode to `owner`. The registrar owns the subnode in between, which
+/// is what allows it to configure the registry and the resolver on the owner's behalf.
+///
+/// If a call fails after the subnode was minted, it is taken back with `unmint_subnode`
+/// rather than left with the registrar.
+async fn mint_subnode_with_records(
+    registry: ActorId,
+    parent: Node,
+    label: U256,
+    owner: ActorId,
+    expiry: u64,
+    resolver: ActorId,
+    addr: Option<ActorId>,
+) -> Option<Node> {
+    let subnode = mint_subnode(registry, parent, label, exec::program_id(), expiry).await?;
+    if configure_and_hand_over(registry, subnode, owner, resolver, addr).await.is_none() {
+        unmint_subnode(registry, parent, label, subnode).await;
+        return None;
+    }
+    Some(subnode)
+}
+
+/// Set the resolver and `addr` record of a subnode the registrar owns, then hand it to
+/// `owner`.
+async fn configure_and_hand_over(
+    registry: ActorId,
+    subnode: Node,
+    owner: ActorId,
+    resolver: ActorId,
+    addr: Option<ActorId>,
+) -> Option<()> {
+    let request = registry_io::SetResolver::encode_call(subnode, resolver);
+    call::<registry_io::SetResolver>(registry, request).await?.ok()?;
+
+    if let Some(addr) = addr {
+        let request = resolver_io::SetAddr::encode_call(subnode, addr);
+        call::<resolver_io::SetAddr>(resolver, request).await?.ok()?;
+    }
+
+    let request = registry_io::SetOwner::encode_call(subnode, owner);
+    call::<registry_io::SetOwner>(registry, request).await?.ok()?;
+    Some(())
+}
+
+/// Undo a partly configured subnode still owned by the registrar: clear its resolver and
+/// leave it unowned. Best effort, as nothing more can be done if these calls fail as well;
+/// an `addr` record already set stays in the resolver, but is no longer reachable through
+/// the registry.
+async fn unmint_subnode(registry: ActorId, parent: Node, label: U256, subnode: Node) {
+    let request = registry_io::SetResolver::encode_call(subnode, ActorId::zero());
+    let _ = call::<registry_io::SetResolver>(registry, request).await;
+    let _ = mint_subnode(registry, parent, label, ActorId::zero(), 0).await;
+}
+
 #[sails_rs::service(events = RegistrarEvent)]
 impl Service {
     pub fn new() -> Self { Self }
 
-    pub fn commit(&mut self, commitment: [u8; 32]) -> RegistrarEvent {
+    /// Submit a commitment to a registration, as computed by `make_commitment`
+    pub fn commit(&mut self, commitment: [u8; 32]) -> Result<RegistrarEvent, RegistrarError> {
         let now = sails_rs::gstd::exec::block_timestamp();
         let s = RegistrarState::state_mut();
         if s.commits.contains_key(&commitment) {
-            panic!("Commitment already exists");
+            return Err(RegistrarError::CommitmentAlreadyExists);
         }
         if s.commits.len() >= MAX_COMMITMENTS {
-            panic!("Too many commitments"); 
+            return Err(RegistrarError::TooManyCommitments);
         }
         s.commits.insert(commitment, now);
         self.emit_event(RegistrarEvent::CommitSubmitted { commitment, timestamp: now })
             .expect("Event failed");
-        RegistrarEvent::CommitSubmitted { commitment, timestamp: now }
+        Ok(RegistrarEvent::CommitSubmitted { commitment, timestamp: now })
     }
 
-    /// Register a name after commit-reveal
-    pub fn register(
+    /// Register a name after commit-reveal.
+    ///
+    /// `name` is normalized first (lowercase, NFC); invalid names are rejected with
+    /// `InvalidLabel`. The commitment must be `make_commitment` of the normalized name,
+    /// `owner`, `secret`, `salt`, `duration` and `resolver`.
+    ///
+    /// Mints `name` under `tld_node` in the registry, owned until the end of its grace
+    /// period, so the registrar must own `tld_node` there (or be a registry controller).
+    /// When `resolver` is given, it is set for the new node, together with an initial `addr`
+    /// record if one is supplied, before ownership is handed to `owner`. If any of these
+    /// calls fails, the registrar's expiry and the commitment are restored and
+    /// `RegistryCallFailed` is returned. A node already minted is then reset in the registry
+    /// to no owner and no resolver, as far as the registry lets it; an `addr` record set
+    /// before the failure is left in the resolver.
+    ///
+    /// The attached value must cover `price(name, duration)`; any excess is refunded, and
+    /// the whole value is refunded if registration fails.
+    ///
+    /// On success `owner` also receives the name's token (id = labelhash), taking it from
+    /// the previous holder if the name was registered before.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn register(
         &mut self,
         name: Label,
         owner: ActorId,
@@ -171,219 +687,788 @@ impl Service {
         secret: [u8; 32],
         salt: [u8; 32],
         resolver: Option<ActorId>,
-    ) -> RegistrarEvent {
-        if name.len() > MAX_LABEL_LENGTH {
-            panic!("Name too long"); 
-        }
-        let now = sails_rs::gstd::exec::block_timestamp();
-        let s = RegistrarState::state_mut();
-
-        if s.reserved.contains(&name) {
-            panic!("Name is reserved");
-        }
+        addr: Option<ActorId>,
+    ) -> Result<RegistrarEvent, RegistrarError> {
+        // Read before awaiting: after the reply arrives these refer to the reply message.
+        let (caller, paid) = (msg::source(), msg::value());
 
-      
-        let mut preimage = Vec::new();
-        preimage.extend_from_slice(&name);
-        preimage.extend_from_slice(owner.as_ref());
-        preimage.extend_from_slice(&secret);
-        preimage.extend_from_slice(&salt);
-      
-        let commitment = blake2_256(&preimage);
-
-        let commit_time = s.commits.get(&commitment).copied().unwrap_or(0);
-        if commit_time == 0 {
-            panic!("No valid commitment");
-        }
-        let min_age = s.min_commit_age;
-        let max_age = s.max_commit_age;
-        if now < commit_time.checked_add(min_age).expect("Overflow in min age check") {
-            panic!("Commitment too new");
-        }
-        if now > commit_time.checked_add(max_age).expect("Overflow in max age check") {
-            panic!("Commitment expired");
-        }
-
-        let expires_at = s.expires.get(&name).copied().unwrap_or(0);
-        if now <= expires_at.checked_add(s.grace_period).expect("Overflow in grace period check") {
-            panic!("Name not available");
-        }
-
-        let price = Self::calc_price(&name, duration, s.base_price, s.premium_price);
-
-        s.balance = s.balance.saturating_add(price); 
-
-        let new_expiry = now.checked_add(duration).expect("Overflow in expiry calculation");
-        s.expires.insert(name.clone(), new_expiry);
-
-        s.commits.remove(&commitment);
-
-        if let Some(resolver_addr) = resolver {
-            let _ = resolver_addr;
-        }
+        let result = Self::claim(&name, owner, duration, secret, salt, resolver, addr).await;
+        let cost = result.as_ref().map_or(0, |claimed| claimed.price);
+        settle_payment(caller, paid, cost);
+        let Claimed { name, expires, price: cost, from, token_id } = result?;
 
+        self.emit_event(RegistrarEvent::Transfer { from, to: owner, token_id })
+            .expect("Event failed");
         self.emit_event(RegistrarEvent::NameRegistered {
             name: name.clone(),
             owner,
-            expires: new_expiry,
-            cost: price,
+            expires,
+            cost,
         }).expect("Event failed");
-        RegistrarEvent::NameRegistered {
+        Ok(RegistrarEvent::NameRegistered {
             name,
             owner,
-            expires: new_expiry,
-            cost: price,
-        }
+            expires,
+            cost,
+        })
+    }
+
+    /// Renew a name, given in any form that normalizes to it. The attached value must cover
+    /// the price; any excess is refunded.
+    ///
+    /// The name's expiry in the registry is extended as well; if that call fails the
+    /// renewal is undone and `RegistryCallFailed` is returned.
+    pub async fn renew(&mut self, name: Label, duration: u64) -> Result<RegistrarEvent, RegistrarError> {
+        let (caller, paid) = (msg::source(), msg::value());
+        let result = Self::extend(&name, duration).await;
+        let cost = result.as_ref().map_or(0, |(_, _, price)| *price);
+        settle_payment(caller, paid, cost);
+        let (name, expires, cost) = result?;
+
+        self.emit_event(RegistrarEvent::NameRenewed {
+            name: name.clone(),
+            expires,
+            cost,
+        }).expect("Event failed");
+        Ok(RegistrarEvent::NameRenewed {
+            name,
+            expires,
+            cost,
+        })
     }
 
-    /// Renew a name
-    pub fn renew(&mut self, name: Label, duration: u64) -> RegistrarEvent {
-        if name.len() > MAX_LABEL_LENGTH {
-            panic!("Name too long"); 
+    /// Admin: open a sealed-bid auction for the label hashing to `labelhash`. Bids can be
+    /// sealed for `bidding_period` and revealed during the following `reveal_period`;
+    /// meanwhile the name cannot be registered through commit-reveal.
+    pub fn start_auction(&mut self, labelhash: U256) -> Result<RegistrarEvent, RegistrarError> {
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let s = RegistrarState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
+        }
+        if s.auctions.contains_key(&labelhash) {
+            return Err(RegistrarError::AuctionAlreadyStarted);
         }
+        if let Some(token) = s.tokens.get(&labelhash) {
+            if s.is_taken(&token.name, now) {
+                return Err(RegistrarError::NameNotAvailable);
+            }
+        }
+        let reveal_at = now.checked_add(s.bidding_period).ok_or(RegistrarError::Overflow)?;
+        let ends_at = reveal_at.checked_add(s.reveal_period).ok_or(RegistrarError::Overflow)?;
+        s.auctions.insert(labelhash, Auction { reveal_at, ends_at, highest: None, second: 0 });
+        self.emit_event(RegistrarEvent::AuctionStarted { labelhash, reveal_at, ends_at })
+            .expect("Event failed");
+        Ok(RegistrarEvent::AuctionStarted { labelhash, reveal_at, ends_at })
+    }
+
+    /// Seal a bid, `hash = bid_hash(name, bidder, value, salt)`, locking `deposit` from the
+    /// attached value; any excess is refunded. The deposit may exceed the bid to hide it,
+    /// and must be at least `min_bid_deposit`.
+    ///
+    /// Sealed bids are not tied to an auction until revealed. A bid never revealed can be
+    /// reclaimed with `reclaim_bid` once any auction it could be revealed in has ended.
+    pub fn seal_bid(&mut self, hash: [u8; 32], deposit: u128) -> Result<RegistrarEvent, RegistrarError> {
+        let (bidder, paid) = (msg::source(), msg::value());
         let now = sails_rs::gstd::exec::block_timestamp();
         let s = RegistrarState::state_mut();
+        let minimum = s.min_bid_deposit();
+        let result = if paid < deposit {
+            Err(RegistrarError::InsufficientPayment { price: deposit, paid })
+        } else if deposit < minimum {
+            Err(RegistrarError::DepositTooLow { minimum })
+        } else if s.sealed_bids.contains_key(&hash) {
+            Err(RegistrarError::CommitmentAlreadyExists)
+        } else if s.sealed_bids.len() >= MAX_SEALED_BIDS {
+            Err(RegistrarError::TooManySealedBids)
+        } else {
+            s.sealed_bids.insert(hash, SealedBid { bidder, deposit, sealed_at: now });
+            Ok(())
+        };
+        let held = if result.is_ok() { deposit } else { 0 };
+        settle_payment(bidder, paid - held, 0);
+        result?;
+
+        self.emit_event(RegistrarEvent::BidSealed { hash, bidder, deposit })
+            .expect("Event failed");
+        Ok(RegistrarEvent::BidSealed { hash, bidder, deposit })
+    }
 
-        let expires_at = s.expires.get(&name).copied().unwrap_or(0);
-        if now > expires_at.checked_add(s.grace_period).expect("Overflow in grace period check") {
-            panic!("Name not renewable");
+    /// Reveal the caller's bid of `value` on `name` during the auction's reveal period.
+    ///
+    /// The bid is accepted if it was sealed before bidding ended, is covered by its deposit
+    /// and is at least the price of registering the name for a year. A bid that is not
+    /// accepted, or is outbid, gets its deposit back; the highest bid keeps it until the
+    /// auction is finalized.
+    pub fn reveal_bid(&mut self, name: Label, value: u128, salt: [u8; 32]) -> Result<RegistrarEvent, RegistrarError> {
+        let bidder = msg::source();
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let name = normalize(&name)?;
+        let s = RegistrarState::state_mut();
+        let labelhash = s.hash_scheme.labelhash(&name);
+        let auction = s.auctions.get(&labelhash).ok_or(RegistrarError::AuctionNotFound)?;
+        if now < auction.reveal_at || now > auction.ends_at {
+            return Err(RegistrarError::NotInRevealPeriod);
         }
+        let reveal_at = auction.reveal_at;
 
-        let price = Self::calc_price(&name, duration, s.base_price, s.premium_price);
+        let hash = bid_hash(&name, bidder, value, salt);
+        let SealedBid { deposit, sealed_at, .. } = s.sealed_bids.remove(&hash).ok_or(RegistrarError::NoValidBid)?;
+        let reserve = s.registration_price(&name, AUCTION_DURATION, now).total();
+        let accepted = sealed_at < reveal_at && value <= deposit && value >= reserve;
 
-        // Payment logic: for demo, just increment balance
-        s.balance = s.balance.saturating_add(price); 
+        let auction = s.auctions.get_mut(&labelhash).ok_or(RegistrarError::AuctionNotFound)?;
+        let mut refunds = Vec::new();
+        match auction.highest {
+            _ if !accepted => refunds.push((bidder, deposit)),
+            Some((outbid, highest, outbid_deposit)) if value > highest => {
+                refunds.push((outbid, outbid_deposit));
+                auction.second = highest;
+                auction.highest = Some((bidder, value, deposit));
+            }
+            Some(_) => {
+                auction.second = auction.second.max(value);
+                refunds.push((bidder, deposit));
+            }
+            None => auction.highest = Some((bidder, value, deposit)),
+        }
+        for (to, amount) in refunds {
+            settle_payment(to, amount, 0);
+        }
 
-        let new_expiry = expires_at.checked_add(duration).expect("Overflow in expiry calculation");
-        s.expires.insert(name.clone(), new_expiry);
+        self.emit_event(RegistrarEvent::BidRevealed { name: name.clone(), bidder, value, accepted })
+            .expect("Event failed");
+        Ok(RegistrarEvent::BidRevealed { name, bidder, value, accepted })
+    }
 
-        self.emit_event(RegistrarEvent::NameRenewed {
-            name: name.clone(),
-            expires: new_expiry,
-            cost: price,
-        }).expect("Event failed");
-        RegistrarEvent::NameRenewed {
-            name,
-            expires: new_expiry,
-            cost: price,
+    /// Refund the deposit of a bid that was never revealed to its bidder, once
+    /// `bidding_period + reveal_period` has passed since it was sealed: by then any auction
+    /// open at the time has ended. The bidder may reclaim their own bids; the admin may
+    /// sweep anyone's.
+    pub fn reclaim_bid(&mut self, hash: [u8; 32]) -> Result<RegistrarEvent, RegistrarError> {
+        let caller = msg::source();
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let s = RegistrarState::state_mut();
+        let bid = s.sealed_bids.get(&hash).ok_or(RegistrarError::NoValidBid)?;
+        if bid.bidder != caller && !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
+        }
+        let locked_for = s.bidding_period.saturating_add(s.reveal_period);
+        if now <= bid.sealed_at.saturating_add(locked_for) {
+            return Err(RegistrarError::BidNotReclaimable);
         }
+        let SealedBid { bidder, deposit, .. } = s.sealed_bids.remove(&hash).ok_or(RegistrarError::NoValidBid)?;
+        settle_payment(bidder, deposit, 0);
+
+        self.emit_event(RegistrarEvent::BidReclaimed { hash, bidder, deposit })
+            .expect("Event failed");
+        Ok(RegistrarEvent::BidReclaimed { hash, bidder, deposit })
     }
 
-    /// Query if a name is available
-    pub fn available(&self, name: Label) -> bool {
-        if name.len() > MAX_LABEL_LENGTH {
-            return false; 
+    /// Close an auction after its reveal period. The highest bidder is registered the name
+    /// for a year and pays the second-highest bid, or the year's price if that is higher;
+    /// the rest of the deposit is refunded. If nobody bid, or the name was reserved or
+    /// registered in the meantime, the auction closes without a winner.
+    ///
+    /// If minting fails the auction is restored and `RegistryCallFailed` is returned.
+    pub async fn finalize_auction(&mut self, name: Label) -> Result<RegistrarEvent, RegistrarError> {
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let name = normalize(&name)?;
+        let s = RegistrarState::state_mut();
+        let labelhash = s.hash_scheme.labelhash(&name);
+        let auction = s.auctions.get(&labelhash).ok_or(RegistrarError::AuctionNotFound)?;
+        if now <= auction.ends_at {
+            return Err(RegistrarError::AuctionNotEnded);
         }
+        let expires = now.checked_add(AUCTION_DURATION).ok_or(RegistrarError::Overflow)?;
+        let auction = s.auctions.remove(&labelhash).ok_or(RegistrarError::AuctionNotFound)?;
+
+        let (winner, price) = match auction.highest {
+            Some((winner, value, deposit)) if !s.is_taken(&name, now) && s.confusable_with(&name, now).is_none() => {
+                let reserve = s.registration_price(&name, AUCTION_DURATION, now).total();
+                let price = auction.second.max(reserve).min(value);
+                match Self::mint_name(&name, winner, expires, None, None).await {
+                    Ok((from, token_id)) => {
+                        settle_payment(winner, deposit, price);
+                        self.emit_event(RegistrarEvent::Transfer { from, to: winner, token_id })
+                            .expect("Event failed");
+                        (Some(winner), price)
+                    }
+                    Err(error) => {
+                        RegistrarState::state_mut().auctions.insert(labelhash, auction);
+                        return Err(error);
+                    }
+                }
+            }
+            Some((bidder, _, deposit)) => {
+                settle_payment(bidder, deposit, 0);
+                (None, 0)
+            }
+            None => (None, 0),
+        };
+        self.emit_event(RegistrarEvent::AuctionFinalized { name: name.clone(), winner, price })
+            .expect("Event failed");
+        Ok(RegistrarEvent::AuctionFinalized { name, winner, price })
+    }
+
+    /// Query if a name is available: neither taken nor confusable with a taken name.
+    /// Fails for names that cannot be registered at all.
+    pub fn available(&self, name: Label) -> Result<bool, RegistrarError> {
+        let name = normalize(&name)?;
         let now = sails_rs::gstd::exec::block_timestamp();
         let s = RegistrarState::state_ref();
-        let expires_at = s.expires.get(&name).copied().unwrap_or(0);
-        !s.reserved.contains(&name) && now > expires_at.saturating_add(s.grace_period)
+        Ok(!s.is_taken(&name, now) && s.confusable_with(&name, now).is_none())
+    }
+
+    /// Query the registered or reserved name that `name` looks like, if any. Allowlisted
+    /// names are never confusable.
+    pub fn confusable_with(&self, name: Label) -> Result<Option<Label>, RegistrarError> {
+        let name = normalize(&name)?;
+        let now = sails_rs::gstd::exec::block_timestamp();
+        Ok(RegistrarState::state_ref().confusable_with(&name, now).cloned())
     }
 
     /// Query expiry of a name
     pub fn expiry_of(&self, name: Label) -> Option<u64> {
-        if name.len() > MAX_LABEL_LENGTH {
-            return None; 
-        }
+        let name = normalize(&name).ok()?;
         RegistrarState::state_ref().expires.get(&name).copied()
     }
 
-    /// Query price for a name and duration
-    pub fn price(&self, name: Label, duration: u64) -> u128 {
-        if name.len() > MAX_LABEL_LENGTH {
-            return 0;
-        }
-        let s = RegistrarState::state_ref();
-        Self::calc_price(&name, duration, s.base_price, s.premium_price)
+    /// Query price of registering a name for a duration; the value to attach is its
+    /// `total()`. Renewals are charged the same, without `expiry_premium`.
+    pub fn price(&self, name: Label, duration: u64) -> Result<PriceBreakdown, RegistrarError> {
+        let name = normalize(&name)?;
+        let now = sails_rs::gstd::exec::block_timestamp();
+        Ok(RegistrarState::state_ref().registration_price(&name, duration, now))
     }
 
-    /// Admin: set prices
-    pub fn set_prices(&mut self, base: u128, premium: u128) -> RegistrarEvent {
+    /// Admin: set the pricing table
+    pub fn set_price_tiers(&mut self, tiers: PriceTiers) -> Result<RegistrarEvent, RegistrarError> {
         let s = RegistrarState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not controller");
+            return Err(RegistrarError::NotController);
+        }
+        if tiers.multi_year_discount > MAX_DISCOUNT {
+            return Err(RegistrarError::InvalidDiscount { discount: tiers.multi_year_discount });
         }
-        s.base_price = base;
-        s.premium_price = premium;
-        self.emit_event(RegistrarEvent::PricesSet { base, premium })
+        s.price_tiers = tiers.clone();
+        self.emit_event(RegistrarEvent::PriceTiersSet { tiers: tiers.clone() })
             .expect("Event failed");
-        RegistrarEvent::PricesSet { base, premium }
+        Ok(RegistrarEvent::PriceTiersSet { tiers })
     }
 
     /// Admin: set commit ages
-    pub fn set_commit_ages(&mut self, min: u64, max: u64) -> RegistrarEvent {
+    pub fn set_commit_ages(&mut self, min: u64, max: u64) -> Result<RegistrarEvent, RegistrarError> {
         let s = RegistrarState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not controller");
+            return Err(RegistrarError::NotController);
         }
         s.min_commit_age = min;
         s.max_commit_age = max;
         self.emit_event(RegistrarEvent::CommitAgesSet { min, max })
             .expect("Event failed");
-        RegistrarEvent::CommitAgesSet { min, max }
+        Ok(RegistrarEvent::CommitAgesSet { min, max })
     }
 
     /// Admin: set grace period
-    pub fn set_grace_period(&mut self, grace: u64) -> RegistrarEvent {
+    pub fn set_grace_period(&mut self, grace: u64) -> Result<RegistrarEvent, RegistrarError> {
         let s = RegistrarState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not controller");
+            return Err(RegistrarError::NotController);
         }
         s.grace_period = grace;
         self.emit_event(RegistrarEvent::GracePeriodSet { grace })
             .expect("Event failed");
-        RegistrarEvent::GracePeriodSet { grace }
+        Ok(RegistrarEvent::GracePeriodSet { grace })
     }
 
-    /// Admin: reserve names
-    pub fn reserve_names(&mut self, labels: Vec<Label>) -> RegistrarEvent {
+    /// Admin: set the premium on recently expired names
+    pub fn set_expiry_premium(&mut self, premium: ExpiryPremium) -> Result<RegistrarEvent, RegistrarError> {
         let s = RegistrarState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not controller");
+            return Err(RegistrarError::NotController);
         }
-        if labels.len() > MAX_LABELS_RESERVED {
-            panic!("Too many labels to reserve"); 
+        s.expiry_premium = premium;
+        self.emit_event(RegistrarEvent::ExpiryPremiumSet { premium })
+            .expect("Event failed");
+        Ok(RegistrarEvent::ExpiryPremiumSet { premium })
+    }
+
+    /// Admin: set how long auctions take bids and then reveals
+    pub fn set_auction_periods(&mut self, bidding: u64, reveal: u64) -> Result<RegistrarEvent, RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
         }
-        for label in &labels {
-            if label.len() > MAX_LABEL_LENGTH {
-                panic!("Label too long"); 
-            }
+        s.bidding_period = bidding;
+        s.reveal_period = reveal;
+        self.emit_event(RegistrarEvent::AuctionPeriodsSet { bidding, reveal })
+            .expect("Event failed");
+        Ok(RegistrarEvent::AuctionPeriodsSet { bidding, reveal })
+    }
+
+    /// Admin: reserve names, stored normalized
+    pub fn reserve_names(&mut self, labels: Vec<Label>) -> Result<RegistrarEvent, RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
         }
+        if labels.len() > MAX_LABELS_RESERVED {
+            return Err(RegistrarError::TooManyLabels);
+        }
+        let labels = labels
+            .iter()
+            .map(|label| normalize(label))
+            .collect::<Result<Vec<_>, _>>()?;
         for label in &labels {
             s.reserved.push(label.clone());
+            s.skeletons.entry(confusables::skeleton(label)).or_default().insert(label.clone());
         }
         self.emit_event(RegistrarEvent::NamesReserved { labels: labels.clone() })
             .expect("Event failed");
-        RegistrarEvent::NamesReserved { labels }
+        Ok(RegistrarEvent::NamesReserved { labels })
     }
 
-    /// Admin: withdraw balance
-    pub fn withdraw(&mut self, to: ActorId, amount: u128) -> RegistrarEvent {
+    /// Admin: allow or disallow registering `name` although it is confusable with another
+    /// name, e.g. for a brand that holds both.
+    pub fn set_confusable_allowed(&mut self, name: Label, allowed: bool) -> Result<RegistrarEvent, RegistrarError> {
         let s = RegistrarState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not controller");
+            return Err(RegistrarError::NotController);
+        }
+        let name = normalize(&name)?;
+        if allowed {
+            s.confusable_allowlist.insert(name.clone());
+        } else {
+            s.confusable_allowlist.remove(&name);
+        }
+        self.emit_event(RegistrarEvent::ConfusableAllowed { name: name.clone(), allowed })
+            .expect("Event failed");
+        Ok(RegistrarEvent::ConfusableAllowed { name, allowed })
+    }
+
+    /// Admin: withdraw unassigned balance, transferring it to `to`
+    pub fn withdraw(&mut self, to: ActorId, amount: u128) -> Result<RegistrarEvent, RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
         }
         if amount > s.balance {
-            panic!("Insufficient balance");
+            return Err(RegistrarError::InsufficientBalance { requested: amount, available: s.balance });
         }
+        transfer(to, amount)?;
         s.balance = s.balance.saturating_sub(amount); 
       
         self.emit_event(RegistrarEvent::Withdrawn { to, amount })
             .expect("Event failed");
-        RegistrarEvent::Withdrawn { to, amount }
+        Ok(RegistrarEvent::Withdrawn { to, amount })
+    }
+
+    /// Admin: set how future revenue is split, as (payee, share in basis points) pairs.
+    /// Shares may add up to less than 10000; the rest stays in the unassigned balance.
+    pub fn set_payees(&mut self, payees: Vec<(ActorId, u16)>) -> Result<RegistrarEvent, RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistrarError::NotController);
+        }
+        if payees.len() > MAX_PAYEES {
+            return Err(RegistrarError::TooManyPayees);
+        }
+        let total: u32 = payees.iter().map(|(_, share)| *share as u32).sum();
+        if total > TOTAL_SHARES as u32 {
+            return Err(RegistrarError::InvalidShares { total });
+        }
+        s.payees = payees.clone();
+        self.emit_event(RegistrarEvent::PayeesSet { payees: payees.clone() })
+            .expect("Event failed");
+        Ok(RegistrarEvent::PayeesSet { payees })
+    }
+
+    /// Transfer everything owed to `payee`. Anyone may trigger a release.
+    pub fn release(&mut self, payee: ActorId) -> Result<RegistrarEvent, RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let amount = s.payee_balances.get(&payee).copied().unwrap_or(0);
+        if amount == 0 {
+            return Err(RegistrarError::NothingToRelease);
+        }
+        transfer(payee, amount)?;
+        s.payee_balances.remove(&payee);
+
+        self.emit_event(RegistrarEvent::Withdrawn { to: payee, amount })
+            .expect("Event failed");
+        Ok(RegistrarEvent::Withdrawn { to: payee, amount })
+    }
+
+    /// Transfer a name's token to `to`. The caller must own the token, be approved for it
+    /// or be an operator of its owner, and the name must not have expired.
+    ///
+    /// Only the token changes hands: the new owner takes over the node in the registry
+    /// with `reclaim`.
+    pub fn transfer(&mut self, to: ActorId, token_id: TokenId) -> Result<RegistrarEvent, RegistrarError> {
+        if to.is_zero() {
+            return Err(RegistrarError::TransferToZeroAddress);
+        }
+        let now = exec::block_timestamp();
+        let s = RegistrarState::state_mut();
+        let from = s.check_approved_or_owner(&msg::source(), &token_id, now)?;
+        let name = s.tokens[&token_id].name.clone();
+        s.move_token(token_id, &name, to);
+
+        self.emit_event(RegistrarEvent::Transfer { from, to, token_id })
+            .expect("Event failed");
+        Ok(RegistrarEvent::Transfer { from, to, token_id })
+    }
+
+    /// Allow `approved` to transfer or reclaim a single token until it next changes hands.
+    /// Approving the zero address clears the approval.
+    pub fn approve(&mut self, approved: ActorId, token_id: TokenId) -> Result<RegistrarEvent, RegistrarError> {
+        let now = exec::block_timestamp();
+        let caller = msg::source();
+        let s = RegistrarState::state_mut();
+        let owner = s.live_token_owner(&token_id, now).ok_or(RegistrarError::TokenNotFound)?;
+        if caller != owner && !s.operator_approvals.contains(&(owner, caller)) {
+            return Err(RegistrarError::NotTokenOwnerOrApproved);
+        }
+        if approved.is_zero() {
+            s.token_approvals.remove(&token_id);
+        } else {
+            s.token_approvals.insert(token_id, approved);
+        }
+
+        self.emit_event(RegistrarEvent::Approval { owner, approved, token_id })
+            .expect("Event failed");
+        Ok(RegistrarEvent::Approval { owner, approved, token_id })
+    }
+
+    /// Allow or disallow `operator` to manage all of the caller's tokens
+    pub fn set_approval_for_all(&mut self, operator: ActorId, approved: bool) -> Result<RegistrarEvent, RegistrarError> {
+        let owner = msg::source();
+        let s = RegistrarState::state_mut();
+        if approved {
+            s.operator_approvals.insert((owner, operator));
+        } else {
+            s.operator_approvals.remove(&(owner, operator));
+        }
+
+        self.emit_event(RegistrarEvent::ApprovalForAll { owner, operator, approved })
+            .expect("Event failed");
+        Ok(RegistrarEvent::ApprovalForAll { owner, operator, approved })
+    }
+
+    /// Set `owner` as the owner of the token's node in the registry, e.g. after buying the
+    /// token. The caller must own the token, be approved for it or be an operator of its
+    /// owner.
+    pub async fn reclaim(&mut self, token_id: TokenId, owner: ActorId) -> Result<RegistrarEvent, RegistrarError> {
+        let now = exec::block_timestamp();
+        let s = RegistrarState::state_ref();
+        s.check_approved_or_owner(&msg::source(), &token_id, now)?;
+        let expires_at = s.expires.get(&s.tokens[&token_id].name).copied().unwrap_or(0);
+        let registry_expiry = expires_at.saturating_add(s.grace_period);
+
+        mint_subnode(s.registry, s.tld_node, token_id, owner, registry_expiry)
+            .await
+            .ok_or(RegistrarError::RegistryCallFailed)?;
+
+        self.emit_event(RegistrarEvent::NameReclaimed { token_id, owner })
+            .expect("Event failed");
+        Ok(RegistrarEvent::NameReclaimed { token_id, owner })
+    }
+
+    /// Query the owner of a token; `None` if it does not exist or its name has expired
+    pub fn owner_of_token(&self, token_id: TokenId) -> Option<ActorId> {
+        RegistrarState::state_ref().live_token_owner(&token_id, exec::block_timestamp())
+    }
+
+    /// Query the number of tokens held by `owner`, including those of expired names
+    pub fn balance_of(&self, owner: ActorId) -> u64 {
+        RegistrarState::state_ref().token_balances.get(&owner).copied().unwrap_or(0)
+    }
+
+    /// Query a page of the names whose tokens `owner` holds, in name order, with their
+    /// expiries and the cursor of the next page. Expired names are included until they are
+    /// registered again.
+    pub fn labels_of(&self, owner: ActorId, cursor: Option<Label>, limit: u32) -> (Vec<(Label, u64)>, Option<Label>) {
+        let s = RegistrarState::state_ref();
+        let Some(names) = s.names_by_owner.get(&owner) else {
+            return (Vec::new(), None);
+        };
+        let (names, next_cursor) = pagination::page_set(names, cursor.as_ref(), limit);
+        let labels = names
+            .into_iter()
+            .map(|name| {
+                let expires = s.expires.get(&name).copied().unwrap_or(0);
+                (name, expires)
+            })
+            .collect();
+        (labels, next_cursor)
+    }
+
+    /// Query the account approved for a token, if any
+    pub fn get_approved(&self, token_id: TokenId) -> Option<ActorId> {
+        RegistrarState::state_ref().token_approvals.get(&token_id).copied()
+    }
+
+    /// Query whether `operator` may manage all of `owner`'s tokens
+    pub fn is_approved_for_all(&self, owner: ActorId, operator: ActorId) -> bool {
+        RegistrarState::state_ref().operator_approvals.contains(&(owner, operator))
+    }
+
+    /// Query the auction of a labelhash, if one is in progress
+    pub fn auction_of(&self, labelhash: U256) -> Option<Auction> {
+        RegistrarState::state_ref().auctions.get(&labelhash).cloned()
+    }
+
+    /// Query the smallest deposit `seal_bid` accepts
+    pub fn min_bid_deposit(&self) -> u128 {
+        RegistrarState::state_ref().min_bid_deposit()
+    }
+
+    /// Query a sealed bid that has not been revealed yet
+    pub fn sealed_bid(&self, hash: [u8; 32]) -> Option<SealedBid> {
+        RegistrarState::state_ref().sealed_bids.get(&hash).cloned()
+    }
+
+    /// Query the sealed bid to pass to `seal_bid` for a bid of `value` on `name`
+    pub fn bid_hash(&self, name: Label, bidder: ActorId, value: u128, salt: [u8; 32]) -> Result<[u8; 32], RegistrarError> {
+        Ok(bid_hash(&normalize(&name)?, bidder, value, salt))
+    }
+
+    /// Query the commitment to pass to `commit` before registering `name` with these
+    /// arguments. The layout, versioned by `COMMITMENT_VERSION`, is documented in
+    /// `vns_common::commitment`.
+    pub fn make_commitment(
+        &self,
+        name: Label,
+        owner: ActorId,
+        secret: [u8; 32],
+        salt: [u8; 32],
+        duration: u64,
+        resolver: Option<ActorId>,
+    ) -> Result<[u8; 32], RegistrarError> {
+        Ok(make_commitment(&normalize(&name)?, owner, secret, salt, duration, resolver))
+    }
+
+    /// Query the commitment layout version `register` accepts
+    pub fn commitment_version(&self) -> u8 {
+        commitment::COMMITMENT_VERSION
+    }
+
+    /// Query the token id of a name
+    pub fn token_id_of(&self, name: Label) -> Result<TokenId, RegistrarError> {
+        let name = normalize(&name)?;
+        Ok(RegistrarState::state_ref().hash_scheme.labelhash(&name))
+    }
+
+    /// Query registrar settings with a page of registered names, starting after `cursor`
+    pub fn query_state(&self, cursor: Option<Label>, limit: u32) -> IoRegistrarState {
+        RegistrarState::state_ref().io_state(cursor.as_ref(), limit)
+    }
+
+    /// Query a page of registered names and their expiries, in name order, with the cursor
+    /// of the next page
+    pub fn expiries_page(&self, cursor: Option<Label>, limit: u32) -> (Vec<(Label, u64)>, Option<Label>) {
+        pagination::page(&RegistrarState::state_ref().expires, cursor.as_ref(), limit)
+    }
+
+    /// Query a page of pending commitments and their timestamps, with the cursor of the
+    /// next page
+    pub fn commits_page(&self, cursor: Option<Commitment>, limit: u32) -> (Vec<(Commitment, u64)>, Option<Commitment>) {
+        pagination::page(&RegistrarState::state_ref().commits, cursor.as_ref(), limit)
+    }
+
+    /// Query revenue owed to a payee
+    pub fn payee_balance(&self, payee: ActorId) -> u128 {
+        RegistrarState::state_ref().payee_balances.get(&payee).copied().unwrap_or(0)
+    }
+
+    /// Query the configured revenue split
+    pub fn payees(&self) -> Vec<(ActorId, u16)> {
+        RegistrarState::state_ref().payees.clone()
+    }
+
+    /// Validate a registration and mint the name in the registry and as a token, returning
+    /// the new expiry, the price charged for it and the token's previous owner and id.
+    async fn claim(
+        name: &Label,
+        owner: ActorId,
+        duration: u64,
+        secret: [u8; 32],
+        salt: [u8; 32],
+        resolver: Option<ActorId>,
+        addr: Option<ActorId>,
+    ) -> Result<Claimed, RegistrarError> {
+        let name = &normalize(name)?;
+        if addr.is_some() && resolver.is_none() {
+            return Err(RegistrarError::AddrRequiresResolver);
+        }
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let s = RegistrarState::state_mut();
+
+        if s.reserved.contains(name) {
+            return Err(RegistrarError::NameReserved);
+        }
+        if s.auctions.contains_key(&s.hash_scheme.labelhash(name)) {
+            return Err(RegistrarError::NameInAuction);
+        }
+
+        let commitment = make_commitment(name, owner, secret, salt, duration, resolver);
+
+        let commit_time = s.commits.get(&commitment).copied().unwrap_or(0);
+        if commit_time == 0 {
+            return Err(RegistrarError::NoValidCommitment);
+        }
+        let min_age = s.min_commit_age;
+        let max_age = s.max_commit_age;
+        if now < commit_time.checked_add(min_age).ok_or(RegistrarError::Overflow)? {
+            return Err(RegistrarError::CommitmentTooNew);
+        }
+        if now > commit_time.checked_add(max_age).ok_or(RegistrarError::Overflow)? {
+            return Err(RegistrarError::CommitmentExpired);
+        }
+
+        let expires_at = s.expires.get(name).copied().unwrap_or(0);
+        if now <= expires_at.checked_add(s.grace_period).ok_or(RegistrarError::Overflow)? {
+            return Err(RegistrarError::NameNotAvailable);
+        }
+        if let Some(other) = s.confusable_with(name, now) {
+            return Err(RegistrarError::ConfusableName { with: other.clone() });
+        }
+
+        let price = s.registration_price(name, duration, now).total();
+        let paid = msg::value();
+        if paid < price {
+            return Err(RegistrarError::InsufficientPayment { price, paid });
+        }
+
+        let new_expiry = now.checked_add(duration).ok_or(RegistrarError::Overflow)?;
+
+        // Consume the commitment before awaiting the registry, so that it cannot be used
+        // twice, and give it back if the name could not be minted.
+        s.commits.remove(&commitment);
+        let (from, token_id) = match Self::mint_name(name, owner, new_expiry, resolver, addr).await {
+            Ok(minted) => minted,
+            Err(error) => {
+                RegistrarState::state_mut().commits.insert(commitment, commit_time);
+                return Err(error);
+            }
+        };
+        Ok(Claimed { name: name.clone(), expires: new_expiry, price, from, token_id })
+    }
+
+    /// Register `name` until `expires` and mint it to `owner` in the registry, with its
+    /// resolver and `addr` record if given. Returns the previous holder of the name's token
+    /// and the token id.
+    async fn mint_name(
+        name: &Label,
+        owner: ActorId,
+        expires: u64,
+        resolver: Option<ActorId>,
+        addr: Option<ActorId>,
+    ) -> Result<(ActorId, TokenId), RegistrarError> {
+        let s = RegistrarState::state_mut();
+        let registry_expiry = expires.checked_add(s.grace_period).ok_or(RegistrarError::Overflow)?;
+
+        // Claim the name before awaiting the registry, so that no other registration of the
+        // same name, or of a confusable one, can interleave with this one.
+        let previous_expiry = s.expires.insert(name.clone(), expires);
+        let skeleton = confusables::skeleton(name);
+        let skeleton_added = s.skeletons.entry(skeleton.clone()).or_default().insert(name.clone());
+
+        let label = s.hash_scheme.labelhash(name);
+        let (registry, tld_node) = (s.registry, s.tld_node);
+        let minted = match resolver {
+            Some(resolver) => {
+                mint_subnode_with_records(registry, tld_node, label, owner, registry_expiry, resolver, addr).await
+            }
+            None => mint_subnode(registry, tld_node, label, owner, registry_expiry).await,
+        };
+
+        // State is persisted while waiting for the reply: re-borrow it and undo the claim
+        // explicitly on failure, as returning an error does not revert anything.
+        let s = RegistrarState::state_mut();
+        if minted.is_none() {
+            match previous_expiry {
+                Some(expiry) => s.expires.insert(name.clone(), expiry),
+                None => s.expires.remove(name),
+            };
+            if skeleton_added {
+                if let Some(names) = s.skeletons.get_mut(&skeleton) {
+                    names.remove(name);
+                    if names.is_empty() {
+                        s.skeletons.remove(&skeleton);
+                    }
+                }
+            }
+            return Err(RegistrarError::RegistryCallFailed);
+        }
+
+        // Let the registry name the node. Only used for display, so the reply is not awaited.
+        if let Ok(plaintext) = core::str::from_utf8(name) {
+            let request = registry_io::AddPreimage::encode_call(tld_node, plaintext.into());
+            let _ = msg::send_bytes(registry, request, 0);
+        }
+
+        Ok((s.move_token(label, name, owner), label))
+    }
+
+    /// Validate a renewal and extend the expiry, in the registrar and in the registry,
+    /// returning the normalized name, its new expiry and the price charged for it.
+    async fn extend(name: &Label, duration: u64) -> Result<(Label, u64, u128), RegistrarError> {
+        let name = &normalize(name)?;
+        let now = sails_rs::gstd::exec::block_timestamp();
+        let s = RegistrarState::state_mut();
+
+        let expires_at = s.expires.get(name).copied().unwrap_or(0);
+        if now > expires_at.checked_add(s.grace_period).ok_or(RegistrarError::Overflow)? {
+            return Err(RegistrarError::NameNotRenewable);
+        }
+
+        let price = Self::calc_price(name, duration, &s.price_tiers).total();
+        let paid = msg::value();
+        if paid < price {
+            return Err(RegistrarError::InsufficientPayment { price, paid });
+        }
+
+        let new_expiry = expires_at.checked_add(duration).ok_or(RegistrarError::Overflow)?;
+        let registry_expiry = new_expiry.checked_add(s.grace_period).ok_or(RegistrarError::Overflow)?;
+        s.expires.insert(name.clone(), new_expiry);
+
+        let label = s.hash_scheme.labelhash(name);
+        let request = registry_io::SetSubnodeExpiry::encode_call(s.tld_node, label, registry_expiry);
+        if !matches!(call::<registry_io::SetSubnodeExpiry>(s.registry, request).await, Some(Ok(_))) {
+            // Only take back this renewal: others may have extended the name meanwhile.
+            if let Some(expiry) = RegistrarState::state_mut().expires.get_mut(name) {
+                *expiry = expiry.saturating_sub(duration);
+            }
+            return Err(RegistrarError::RegistryCallFailed);
+        }
+        Ok((name.clone(), new_expiry, price))
     }
 
-  
+    /// Price of `duration` for a normalized name, pro rata of the yearly tier prices
+    fn calc_price(name: &Label, duration: u64, tiers: &PriceTiers) -> PriceBreakdown {
+        let chars = core::str::from_utf8(name).map_or(name.len(), |name| name.chars().count());
+        let tier = tiers.per_year[chars.clamp(1, PRICE_TIERS) - 1];
+        let base_tier = tiers.per_year[PRICE_TIERS - 1];
+        let pro_rata = |per_year: u128| per_year.saturating_mul(duration as u128) / YEAR_MS as u128;
 
-    fn calc_price(name: &Label, duration: u64, base: u128, premium: u128) -> u128 {
-        let len = name.len() as u128;
-        let premium_fee = if len < 5 { premium } else { 0 };
-        base.saturating_add(premium_fee)
-            .saturating_mul(duration as u128) 
+        let base = pro_rata(base_tier);
+        let premium = pro_rata(tier.saturating_sub(base_tier));
+        let discount = if duration >= 2 * YEAR_MS {
+            base.saturating_add(premium).saturating_mul(tiers.multi_year_discount as u128) / MAX_DISCOUNT as u128
+        } else {
+            0
+        };
+        PriceBreakdown { base, premium, discount, expiry_premium: 0 }
     }
 }
@@ -0,0 +1,1663 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    fn new(&self, init: InitRegistrar) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), init)
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(init: super::InitRegistrar) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&init)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = super::InitRegistrar;
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Allow `approved` to transfer or reclaim a single token until it next changes hands.
+    /// Approving the zero address clears the approval.
+    fn approve(
+        &mut self,
+        approved: ActorId,
+        token_id: U256,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Approve>::new(self.remoting.clone(), (approved, token_id))
+    }
+    /// Submit a commitment to a registration, as computed by `make_commitment`
+    fn commit(
+        &mut self,
+        commitment: [u8; 32],
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Commit>::new(self.remoting.clone(), commitment)
+    }
+    /// Close an auction after its reveal period. The highest bidder is registered the name
+    /// for a year and pays the second-highest bid, or the year's price if that is higher;
+    /// the rest of the deposit is refunded. If nobody bid, or the name was reserved or
+    /// registered in the meantime, the auction closes without a winner.
+    ///
+    /// If minting fails the auction is restored and `RegistryCallFailed` is returned.
+    fn finalize_auction(
+        &mut self,
+        name: Vec<u8>,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::FinalizeAuction>::new(self.remoting.clone(), name)
+    }
+    /// Set `owner` as the owner of the token's node in the registry, e.g. after buying the
+    /// token. The caller must own the token, be approved for it or be an operator of its
+    /// owner.
+    fn reclaim(
+        &mut self,
+        token_id: U256,
+        owner: ActorId,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Reclaim>::new(self.remoting.clone(), (token_id, owner))
+    }
+    /// Refund the deposit of a bid that was never revealed to its bidder, once
+    /// `bidding_period + reveal_period` has passed since it was sealed: by then any auction
+    /// open at the time has ended. The bidder may reclaim their own bids; the admin may
+    /// sweep anyone's.
+    fn reclaim_bid(
+        &mut self,
+        hash: [u8; 32],
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::ReclaimBid>::new(self.remoting.clone(), hash)
+    }
+    /// Register a name after commit-reveal.
+    ///
+    /// `name` is normalized first (lowercase, NFC); invalid names are rejected with
+    /// `InvalidLabel`. The commitment must be `make_commitment` of the normalized name,
+    /// `owner`, `secret`, `salt`, `duration` and `resolver`.
+    ///
+    /// Mints `name` under `tld_node` in the registry, owned until the end of its grace
+    /// period, so the registrar must own `tld_node` there (or be a registry controller).
+    /// When `resolver` is given, it is set for the new node, together with an initial `addr`
+    /// record if one is supplied, before ownership is handed to `owner`. If any of these
+    /// calls fails, the registrar's expiry and the commitment are restored and
+    /// `RegistryCallFailed` is returned. A node already minted is then reset in the registry
+    /// to no owner and no resolver, as far as the registry lets it; an `addr` record set
+    /// before the failure is left in the resolver.
+    ///
+    /// The attached value must cover `price(name, duration)`; any excess is refunded, and
+    /// the whole value is refunded if registration fails.
+    ///
+    /// On success `owner` also receives the name's token (id = labelhash), taking it from
+    /// the previous holder if the name was registered before.
+    fn register(
+        &mut self,
+        name: Vec<u8>,
+        owner: ActorId,
+        duration: u64,
+        secret: [u8; 32],
+        salt: [u8; 32],
+        resolver: Option<ActorId>,
+        addr: Option<ActorId>,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Register>::new(
+            self.remoting.clone(),
+            (name, owner, duration, secret, salt, resolver, addr),
+        )
+    }
+    /// Transfer everything owed to `payee`. Anyone may trigger a release.
+    fn release(
+        &mut self,
+        payee: ActorId,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Release>::new(self.remoting.clone(), payee)
+    }
+    /// Renew a name, given in any form that normalizes to it. The attached value must cover
+    /// the price; any excess is refunded.
+    ///
+    /// The name's expiry in the registry is extended as well; if that call fails the
+    /// renewal is undone and `RegistryCallFailed` is returned.
+    fn renew(
+        &mut self,
+        name: Vec<u8>,
+        duration: u64,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Renew>::new(self.remoting.clone(), (name, duration))
+    }
+    /// Admin: reserve names, stored normalized
+    fn reserve_names(
+        &mut self,
+        labels: Vec<Vec<u8>>,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::ReserveNames>::new(self.remoting.clone(), labels)
+    }
+    /// Reveal the caller's bid of `value` on `name` during the auction's reveal period.
+    ///
+    /// The bid is accepted if it was sealed before bidding ended, is covered by its deposit
+    /// and is at least the price of registering the name for a year. A bid that is not
+    /// accepted, or is outbid, gets its deposit back; the highest bid keeps it until the
+    /// auction is finalized.
+    fn reveal_bid(
+        &mut self,
+        name: Vec<u8>,
+        value: u128,
+        salt: [u8; 32],
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::RevealBid>::new(self.remoting.clone(), (name, value, salt))
+    }
+    /// Seal a bid, `hash = bid_hash(name, bidder, value, salt)`, locking `deposit` from the
+    /// attached value; any excess is refunded. The deposit may exceed the bid to hide it,
+    /// and must be at least `min_bid_deposit`.
+    ///
+    /// Sealed bids are not tied to an auction until revealed. A bid never revealed can be
+    /// reclaimed with `reclaim_bid` once any auction it could be revealed in has ended.
+    fn seal_bid(
+        &mut self,
+        hash: [u8; 32],
+        deposit: u128,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SealBid>::new(self.remoting.clone(), (hash, deposit))
+    }
+    /// Allow or disallow `operator` to manage all of the caller's tokens
+    fn set_approval_for_all(
+        &mut self,
+        operator: ActorId,
+        approved: bool,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetApprovalForAll>::new(
+            self.remoting.clone(),
+            (operator, approved),
+        )
+    }
+    /// Admin: set how long auctions take bids and then reveals
+    fn set_auction_periods(
+        &mut self,
+        bidding: u64,
+        reveal: u64,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetAuctionPeriods>::new(
+            self.remoting.clone(),
+            (bidding, reveal),
+        )
+    }
+    /// Admin: set commit ages
+    fn set_commit_ages(
+        &mut self,
+        min: u64,
+        max: u64,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetCommitAges>::new(self.remoting.clone(), (min, max))
+    }
+    /// Admin: allow or disallow registering `name` although it is confusable with another
+    /// name, e.g. for a brand that holds both.
+    fn set_confusable_allowed(
+        &mut self,
+        name: Vec<u8>,
+        allowed: bool,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetConfusableAllowed>::new(
+            self.remoting.clone(),
+            (name, allowed),
+        )
+    }
+    /// Admin: set the premium on recently expired names
+    fn set_expiry_premium(
+        &mut self,
+        premium: ExpiryPremium,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetExpiryPremium>::new(self.remoting.clone(), premium)
+    }
+    /// Admin: set grace period
+    fn set_grace_period(
+        &mut self,
+        grace: u64,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetGracePeriod>::new(self.remoting.clone(), grace)
+    }
+    /// Admin: set how future revenue is split, as (payee, share in basis points) pairs.
+    /// Shares may add up to less than 10000; the rest stays in the unassigned balance.
+    fn set_payees(
+        &mut self,
+        payees: Vec<(ActorId, u16)>,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetPayees>::new(self.remoting.clone(), payees)
+    }
+    /// Admin: set the pricing table
+    fn set_price_tiers(
+        &mut self,
+        tiers: PriceTiers,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetPriceTiers>::new(self.remoting.clone(), tiers)
+    }
+    /// Admin: open a sealed-bid auction for the label hashing to `labelhash`. Bids can be
+    /// sealed for `bidding_period` and revealed during the following `reveal_period`;
+    /// meanwhile the name cannot be registered through commit-reveal.
+    fn start_auction(
+        &mut self,
+        labelhash: U256,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::StartAuction>::new(self.remoting.clone(), labelhash)
+    }
+    /// Transfer a name's token to `to`. The caller must own the token, be approved for it
+    /// or be an operator of its owner, and the name must not have expired.
+    ///
+    /// Only the token changes hands: the new owner takes over the node in the registry
+    /// with `reclaim`.
+    fn transfer(
+        &mut self,
+        to: ActorId,
+        token_id: U256,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Transfer>::new(self.remoting.clone(), (to, token_id))
+    }
+    /// Admin: withdraw unassigned balance, transferring it to `to`
+    fn withdraw(
+        &mut self,
+        to: ActorId,
+        amount: u128,
+    ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Withdraw>::new(self.remoting.clone(), (to, amount))
+    }
+    /// Query the auction of a labelhash, if one is in progress
+    fn auction_of(&self, labelhash: U256) -> impl Query<Output = Option<Auction>, Args = R::Args> {
+        RemotingAction::<_, service::io::AuctionOf>::new(self.remoting.clone(), labelhash)
+    }
+    /// Query if a name is available: neither taken nor confusable with a taken name.
+    /// Fails for names that cannot be registered at all.
+    fn available(
+        &self,
+        name: Vec<u8>,
+    ) -> impl Query<Output = Result<bool, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Available>::new(self.remoting.clone(), name)
+    }
+    /// Query the number of tokens held by `owner`, including those of expired names
+    fn balance_of(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, service::io::BalanceOf>::new(self.remoting.clone(), owner)
+    }
+    /// Query the sealed bid to pass to `seal_bid` for a bid of `value` on `name`
+    fn bid_hash(
+        &self,
+        name: Vec<u8>,
+        bidder: ActorId,
+        value: u128,
+        salt: [u8; 32],
+    ) -> impl Query<Output = Result<[u8; 32], RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::BidHash>::new(
+            self.remoting.clone(),
+            (name, bidder, value, salt),
+        )
+    }
+    /// Query the commitment layout version `register` accepts
+    fn commitment_version(&self) -> impl Query<Output = u8, Args = R::Args> {
+        RemotingAction::<_, service::io::CommitmentVersion>::new(self.remoting.clone(), ())
+    }
+    /// Query a page of pending commitments and their timestamps, with the cursor of the
+    /// next page
+    fn commits_page(
+        &self,
+        cursor: Option<[u8; 32]>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<([u8; 32], u64)>, Option<[u8; 32]>), Args = R::Args> {
+        RemotingAction::<_, service::io::CommitsPage>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// Query the registered or reserved name that `name` looks like, if any. Allowlisted
+    /// names are never confusable.
+    fn confusable_with(
+        &self,
+        name: Vec<u8>,
+    ) -> impl Query<Output = Result<Option<Vec<u8>>, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::ConfusableWith>::new(self.remoting.clone(), name)
+    }
+    /// Query a page of registered names and their expiries, in name order, with the cursor
+    /// of the next page
+    fn expiries_page(
+        &self,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>), Args = R::Args> {
+        RemotingAction::<_, service::io::ExpiriesPage>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// Query expiry of a name
+    fn expiry_of(&self, name: Vec<u8>) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, service::io::ExpiryOf>::new(self.remoting.clone(), name)
+    }
+    /// Query the account approved for a token, if any
+    fn get_approved(&self, token_id: U256) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::GetApproved>::new(self.remoting.clone(), token_id)
+    }
+    /// Query whether `operator` may manage all of `owner`'s tokens
+    fn is_approved_for_all(
+        &self,
+        owner: ActorId,
+        operator: ActorId,
+    ) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, service::io::IsApprovedForAll>::new(
+            self.remoting.clone(),
+            (owner, operator),
+        )
+    }
+    /// Query a page of the names whose tokens `owner` holds, in name order, with their
+    /// expiries and the cursor of the next page. Expired names are included until they are
+    /// registered again.
+    fn labels_of(
+        &self,
+        owner: ActorId,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>), Args = R::Args> {
+        RemotingAction::<_, service::io::LabelsOf>::new(
+            self.remoting.clone(),
+            (owner, cursor, limit),
+        )
+    }
+    /// Query the commitment to pass to `commit` before registering `name` with these
+    /// arguments. The layout, versioned by `COMMITMENT_VERSION`, is documented in
+    /// `vns_common::commitment`.
+    fn make_commitment(
+        &self,
+        name: Vec<u8>,
+        owner: ActorId,
+        secret: [u8; 32],
+        salt: [u8; 32],
+        duration: u64,
+        resolver: Option<ActorId>,
+    ) -> impl Query<Output = Result<[u8; 32], RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::MakeCommitment>::new(
+            self.remoting.clone(),
+            (name, owner, secret, salt, duration, resolver),
+        )
+    }
+    /// Query the smallest deposit `seal_bid` accepts
+    fn min_bid_deposit(&self) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, service::io::MinBidDeposit>::new(self.remoting.clone(), ())
+    }
+    /// Query the owner of a token; `None` if it does not exist or its name has expired
+    fn owner_of_token(
+        &self,
+        token_id: U256,
+    ) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::OwnerOfToken>::new(self.remoting.clone(), token_id)
+    }
+    /// Query revenue owed to a payee
+    fn payee_balance(&self, payee: ActorId) -> impl Query<Output = u128, Args = R::Args> {
+        RemotingAction::<_, service::io::PayeeBalance>::new(self.remoting.clone(), payee)
+    }
+    /// Query the configured revenue split
+    fn payees(&self) -> impl Query<Output = Vec<(ActorId, u16)>, Args = R::Args> {
+        RemotingAction::<_, service::io::Payees>::new(self.remoting.clone(), ())
+    }
+    /// Query price of registering a name for a duration; the value to attach is its
+    /// `total()`. Renewals are charged the same, without `expiry_premium`.
+    fn price(
+        &self,
+        name: Vec<u8>,
+        duration: u64,
+    ) -> impl Query<Output = Result<PriceBreakdown, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Price>::new(self.remoting.clone(), (name, duration))
+    }
+    /// Query registrar settings with a page of registered names, starting after `cursor`
+    fn query_state(
+        &self,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> impl Query<Output = IoRegistrarState, Args = R::Args> {
+        RemotingAction::<_, service::io::QueryState>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// Query a sealed bid that has not been revealed yet
+    fn sealed_bid(&self, hash: [u8; 32]) -> impl Query<Output = Option<SealedBid>, Args = R::Args> {
+        RemotingAction::<_, service::io::SealedBid>::new(self.remoting.clone(), hash)
+    }
+    /// Query the token id of a name
+    fn token_id_of(
+        &self,
+        name: Vec<u8>,
+    ) -> impl Query<Output = Result<U256, RegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::TokenIdOf>::new(self.remoting.clone(), name)
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Approve(());
+        impl Approve {
+            #[allow(dead_code)]
+            pub fn encode_call(approved: ActorId, token_id: U256) -> Vec<u8> {
+                <Approve as ActionIo>::encode_call(&(approved, token_id))
+            }
+        }
+        impl ActionIo for Approve {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 65, 112, 112, 114, 111, 118, 101,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Commit(());
+        impl Commit {
+            #[allow(dead_code)]
+            pub fn encode_call(commitment: [u8; 32]) -> Vec<u8> {
+                <Commit as ActionIo>::encode_call(&commitment)
+            }
+        }
+        impl ActionIo for Commit {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 67, 111, 109, 109, 105, 116,
+            ];
+            type Params = [u8; 32];
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct FinalizeAuction(());
+        impl FinalizeAuction {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>) -> Vec<u8> {
+                <FinalizeAuction as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for FinalizeAuction {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 60, 70, 105, 110, 97, 108, 105, 122, 101, 65,
+                117, 99, 116, 105, 111, 110,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Reclaim(());
+        impl Reclaim {
+            #[allow(dead_code)]
+            pub fn encode_call(token_id: U256, owner: ActorId) -> Vec<u8> {
+                <Reclaim as ActionIo>::encode_call(&(token_id, owner))
+            }
+        }
+        impl ActionIo for Reclaim {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 82, 101, 99, 108, 97, 105, 109,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct ReclaimBid(());
+        impl ReclaimBid {
+            #[allow(dead_code)]
+            pub fn encode_call(hash: [u8; 32]) -> Vec<u8> {
+                <ReclaimBid as ActionIo>::encode_call(&hash)
+            }
+        }
+        impl ActionIo for ReclaimBid {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101, 99, 108, 97, 105, 109, 66, 105,
+                100,
+            ];
+            type Params = [u8; 32];
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Register(());
+        impl Register {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                name: Vec<u8>,
+                owner: ActorId,
+                duration: u64,
+                secret: [u8; 32],
+                salt: [u8; 32],
+                resolver: Option<ActorId>,
+                addr: Option<ActorId>,
+            ) -> Vec<u8> {
+                <Register as ActionIo>::encode_call(&(
+                    name, owner, duration, secret, salt, resolver, addr,
+                ))
+            }
+        }
+        impl ActionIo for Register {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 82, 101, 103, 105, 115, 116, 101, 114,
+            ];
+            type Params = (
+                Vec<u8>,
+                ActorId,
+                u64,
+                [u8; 32],
+                [u8; 32],
+                Option<ActorId>,
+                Option<ActorId>,
+            );
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Release(());
+        impl Release {
+            #[allow(dead_code)]
+            pub fn encode_call(payee: ActorId) -> Vec<u8> {
+                <Release as ActionIo>::encode_call(&payee)
+            }
+        }
+        impl ActionIo for Release {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 82, 101, 108, 101, 97, 115, 101,
+            ];
+            type Params = ActorId;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Renew(());
+        impl Renew {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>, duration: u64) -> Vec<u8> {
+                <Renew as ActionIo>::encode_call(&(name, duration))
+            }
+        }
+        impl ActionIo for Renew {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 20, 82, 101, 110, 101, 119,
+            ];
+            type Params = (Vec<u8>, u64);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct ReserveNames(());
+        impl ReserveNames {
+            #[allow(dead_code)]
+            pub fn encode_call(labels: Vec<Vec<u8>>) -> Vec<u8> {
+                <ReserveNames as ActionIo>::encode_call(&labels)
+            }
+        }
+        impl ActionIo for ReserveNames {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 115, 101, 114, 118, 101, 78, 97,
+                109, 101, 115,
+            ];
+            type Params = Vec<Vec<u8>>;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct RevealBid(());
+        impl RevealBid {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>, value: u128, salt: [u8; 32]) -> Vec<u8> {
+                <RevealBid as ActionIo>::encode_call(&(name, value, salt))
+            }
+        }
+        impl ActionIo for RevealBid {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 82, 101, 118, 101, 97, 108, 66, 105, 100,
+            ];
+            type Params = (Vec<u8>, u128, [u8; 32]);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SealBid(());
+        impl SealBid {
+            #[allow(dead_code)]
+            pub fn encode_call(hash: [u8; 32], deposit: u128) -> Vec<u8> {
+                <SealBid as ActionIo>::encode_call(&(hash, deposit))
+            }
+        }
+        impl ActionIo for SealBid {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 83, 101, 97, 108, 66, 105, 100,
+            ];
+            type Params = ([u8; 32], u128);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetApprovalForAll(());
+        impl SetApprovalForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(operator: ActorId, approved: bool) -> Vec<u8> {
+                <SetApprovalForAll as ActionIo>::encode_call(&(operator, approved))
+            }
+        }
+        impl ActionIo for SetApprovalForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 68, 83, 101, 116, 65, 112, 112, 114, 111, 118,
+                97, 108, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, bool);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetAuctionPeriods(());
+        impl SetAuctionPeriods {
+            #[allow(dead_code)]
+            pub fn encode_call(bidding: u64, reveal: u64) -> Vec<u8> {
+                <SetAuctionPeriods as ActionIo>::encode_call(&(bidding, reveal))
+            }
+        }
+        impl ActionIo for SetAuctionPeriods {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 68, 83, 101, 116, 65, 117, 99, 116, 105, 111,
+                110, 80, 101, 114, 105, 111, 100, 115,
+            ];
+            type Params = (u64, u64);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetCommitAges(());
+        impl SetCommitAges {
+            #[allow(dead_code)]
+            pub fn encode_call(min: u64, max: u64) -> Vec<u8> {
+                <SetCommitAges as ActionIo>::encode_call(&(min, max))
+            }
+        }
+        impl ActionIo for SetCommitAges {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 83, 101, 116, 67, 111, 109, 109, 105, 116,
+                65, 103, 101, 115,
+            ];
+            type Params = (u64, u64);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetConfusableAllowed(());
+        impl SetConfusableAllowed {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>, allowed: bool) -> Vec<u8> {
+                <SetConfusableAllowed as ActionIo>::encode_call(&(name, allowed))
+            }
+        }
+        impl ActionIo for SetConfusableAllowed {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 80, 83, 101, 116, 67, 111, 110, 102, 117, 115,
+                97, 98, 108, 101, 65, 108, 108, 111, 119, 101, 100,
+            ];
+            type Params = (Vec<u8>, bool);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetExpiryPremium(());
+        impl SetExpiryPremium {
+            #[allow(dead_code)]
+            pub fn encode_call(premium: super::ExpiryPremium) -> Vec<u8> {
+                <SetExpiryPremium as ActionIo>::encode_call(&premium)
+            }
+        }
+        impl ActionIo for SetExpiryPremium {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 83, 101, 116, 69, 120, 112, 105, 114, 121,
+                80, 114, 101, 109, 105, 117, 109,
+            ];
+            type Params = super::ExpiryPremium;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetGracePeriod(());
+        impl SetGracePeriod {
+            #[allow(dead_code)]
+            pub fn encode_call(grace: u64) -> Vec<u8> {
+                <SetGracePeriod as ActionIo>::encode_call(&grace)
+            }
+        }
+        impl ActionIo for SetGracePeriod {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101, 116, 71, 114, 97, 99, 101, 80,
+                101, 114, 105, 111, 100,
+            ];
+            type Params = u64;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetPayees(());
+        impl SetPayees {
+            #[allow(dead_code)]
+            pub fn encode_call(payees: Vec<(ActorId, u16)>) -> Vec<u8> {
+                <SetPayees as ActionIo>::encode_call(&payees)
+            }
+        }
+        impl ActionIo for SetPayees {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 83, 101, 116, 80, 97, 121, 101, 101, 115,
+            ];
+            type Params = Vec<(ActorId, u16)>;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct SetPriceTiers(());
+        impl SetPriceTiers {
+            #[allow(dead_code)]
+            pub fn encode_call(tiers: super::PriceTiers) -> Vec<u8> {
+                <SetPriceTiers as ActionIo>::encode_call(&tiers)
+            }
+        }
+        impl ActionIo for SetPriceTiers {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 83, 101, 116, 80, 114, 105, 99, 101, 84,
+                105, 101, 114, 115,
+            ];
+            type Params = super::PriceTiers;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct StartAuction(());
+        impl StartAuction {
+            #[allow(dead_code)]
+            pub fn encode_call(labelhash: U256) -> Vec<u8> {
+                <StartAuction as ActionIo>::encode_call(&labelhash)
+            }
+        }
+        impl ActionIo for StartAuction {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 83, 116, 97, 114, 116, 65, 117, 99, 116,
+                105, 111, 110,
+            ];
+            type Params = U256;
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Transfer(());
+        impl Transfer {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId, token_id: U256) -> Vec<u8> {
+                <Transfer as ActionIo>::encode_call(&(to, token_id))
+            }
+        }
+        impl ActionIo for Transfer {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct Withdraw(());
+        impl Withdraw {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId, amount: u128) -> Vec<u8> {
+                <Withdraw as ActionIo>::encode_call(&(to, amount))
+            }
+        }
+        impl ActionIo for Withdraw {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 87, 105, 116, 104, 100, 114, 97, 119,
+            ];
+            type Params = (ActorId, u128);
+            type Reply = Result<super::RegistrarEvent, super::RegistrarError>;
+        }
+        pub struct AuctionOf(());
+        impl AuctionOf {
+            #[allow(dead_code)]
+            pub fn encode_call(labelhash: U256) -> Vec<u8> {
+                <AuctionOf as ActionIo>::encode_call(&labelhash)
+            }
+        }
+        impl ActionIo for AuctionOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 65, 117, 99, 116, 105, 111, 110, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<super::Auction>;
+        }
+        pub struct Available(());
+        impl Available {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>) -> Vec<u8> {
+                <Available as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for Available {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 65, 118, 97, 105, 108, 97, 98, 108, 101,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Result<bool, super::RegistrarError>;
+        }
+        pub struct BalanceOf(());
+        impl BalanceOf {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <BalanceOf as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for BalanceOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 66, 97, 108, 97, 110, 99, 101, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = u64;
+        }
+        pub struct BidHash(());
+        impl BidHash {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                name: Vec<u8>,
+                bidder: ActorId,
+                value: u128,
+                salt: [u8; 32],
+            ) -> Vec<u8> {
+                <BidHash as ActionIo>::encode_call(&(name, bidder, value, salt))
+            }
+        }
+        impl ActionIo for BidHash {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 66, 105, 100, 72, 97, 115, 104,
+            ];
+            type Params = (Vec<u8>, ActorId, u128, [u8; 32]);
+            type Reply = Result<[u8; 32], super::RegistrarError>;
+        }
+        pub struct CommitmentVersion(());
+        impl CommitmentVersion {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CommitmentVersion as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CommitmentVersion {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 68, 67, 111, 109, 109, 105, 116, 109, 101,
+                110, 116, 86, 101, 114, 115, 105, 111, 110,
+            ];
+            type Params = ();
+            type Reply = u8;
+        }
+        pub struct CommitsPage(());
+        impl CommitsPage {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<[u8; 32]>, limit: u32) -> Vec<u8> {
+                <CommitsPage as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for CommitsPage {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 67, 111, 109, 109, 105, 116, 115, 80, 97,
+                103, 101,
+            ];
+            type Params = (Option<[u8; 32]>, u32);
+            type Reply = (Vec<([u8; 32], u64)>, Option<[u8; 32]>);
+        }
+        pub struct ConfusableWith(());
+        impl ConfusableWith {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>) -> Vec<u8> {
+                <ConfusableWith as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for ConfusableWith {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 67, 111, 110, 102, 117, 115, 97, 98, 108,
+                101, 87, 105, 116, 104,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Result<Option<Vec<u8>>, super::RegistrarError>;
+        }
+        pub struct ExpiriesPage(());
+        impl ExpiriesPage {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<Vec<u8>>, limit: u32) -> Vec<u8> {
+                <ExpiriesPage as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for ExpiriesPage {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 69, 120, 112, 105, 114, 105, 101, 115, 80,
+                97, 103, 101,
+            ];
+            type Params = (Option<Vec<u8>>, u32);
+            type Reply = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>);
+        }
+        pub struct ExpiryOf(());
+        impl ExpiryOf {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>) -> Vec<u8> {
+                <ExpiryOf as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for ExpiryOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 69, 120, 112, 105, 114, 121, 79, 102,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Option<u64>;
+        }
+        pub struct GetApproved(());
+        impl GetApproved {
+            #[allow(dead_code)]
+            pub fn encode_call(token_id: U256) -> Vec<u8> {
+                <GetApproved as ActionIo>::encode_call(&token_id)
+            }
+        }
+        impl ActionIo for GetApproved {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 71, 101, 116, 65, 112, 112, 114, 111, 118,
+                101, 100,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+        pub struct IsApprovedForAll(());
+        impl IsApprovedForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, operator: ActorId) -> Vec<u8> {
+                <IsApprovedForAll as ActionIo>::encode_call(&(owner, operator))
+            }
+        }
+        impl ActionIo for IsApprovedForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 73, 115, 65, 112, 112, 114, 111, 118, 101,
+                100, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = bool;
+        }
+        pub struct LabelsOf(());
+        impl LabelsOf {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, cursor: Option<Vec<u8>>, limit: u32) -> Vec<u8> {
+                <LabelsOf as ActionIo>::encode_call(&(owner, cursor, limit))
+            }
+        }
+        impl ActionIo for LabelsOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 76, 97, 98, 101, 108, 115, 79, 102,
+            ];
+            type Params = (ActorId, Option<Vec<u8>>, u32);
+            type Reply = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>);
+        }
+        pub struct MakeCommitment(());
+        impl MakeCommitment {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                name: Vec<u8>,
+                owner: ActorId,
+                secret: [u8; 32],
+                salt: [u8; 32],
+                duration: u64,
+                resolver: Option<ActorId>,
+            ) -> Vec<u8> {
+                <MakeCommitment as ActionIo>::encode_call(&(
+                    name, owner, secret, salt, duration, resolver,
+                ))
+            }
+        }
+        impl ActionIo for MakeCommitment {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 77, 97, 107, 101, 67, 111, 109, 109, 105,
+                116, 109, 101, 110, 116,
+            ];
+            type Params = (Vec<u8>, ActorId, [u8; 32], [u8; 32], u64, Option<ActorId>);
+            type Reply = Result<[u8; 32], super::RegistrarError>;
+        }
+        pub struct MinBidDeposit(());
+        impl MinBidDeposit {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <MinBidDeposit as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for MinBidDeposit {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 77, 105, 110, 66, 105, 100, 68, 101, 112,
+                111, 115, 105, 116,
+            ];
+            type Params = ();
+            type Reply = u128;
+        }
+        pub struct OwnerOfToken(());
+        impl OwnerOfToken {
+            #[allow(dead_code)]
+            pub fn encode_call(token_id: U256) -> Vec<u8> {
+                <OwnerOfToken as ActionIo>::encode_call(&token_id)
+            }
+        }
+        impl ActionIo for OwnerOfToken {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 79, 119, 110, 101, 114, 79, 102, 84, 111,
+                107, 101, 110,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+        pub struct PayeeBalance(());
+        impl PayeeBalance {
+            #[allow(dead_code)]
+            pub fn encode_call(payee: ActorId) -> Vec<u8> {
+                <PayeeBalance as ActionIo>::encode_call(&payee)
+            }
+        }
+        impl ActionIo for PayeeBalance {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 80, 97, 121, 101, 101, 66, 97, 108, 97,
+                110, 99, 101,
+            ];
+            type Params = ActorId;
+            type Reply = u128;
+        }
+        pub struct Payees(());
+        impl Payees {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Payees as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Payees {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 80, 97, 121, 101, 101, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<(ActorId, u16)>;
+        }
+        pub struct Price(());
+        impl Price {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>, duration: u64) -> Vec<u8> {
+                <Price as ActionIo>::encode_call(&(name, duration))
+            }
+        }
+        impl ActionIo for Price {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 20, 80, 114, 105, 99, 101,
+            ];
+            type Params = (Vec<u8>, u64);
+            type Reply = Result<super::PriceBreakdown, super::RegistrarError>;
+        }
+        pub struct QueryState(());
+        impl QueryState {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<Vec<u8>>, limit: u32) -> Vec<u8> {
+                <QueryState as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for QueryState {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 81, 117, 101, 114, 121, 83, 116, 97, 116,
+                101,
+            ];
+            type Params = (Option<Vec<u8>>, u32);
+            type Reply = super::IoRegistrarState;
+        }
+        pub struct SealedBid(());
+        impl SealedBid {
+            #[allow(dead_code)]
+            pub fn encode_call(hash: [u8; 32]) -> Vec<u8> {
+                <SealedBid as ActionIo>::encode_call(&hash)
+            }
+        }
+        impl ActionIo for SealedBid {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 83, 101, 97, 108, 101, 100, 66, 105, 100,
+            ];
+            type Params = [u8; 32];
+            type Reply = Option<super::SealedBid>;
+        }
+        pub struct TokenIdOf(());
+        impl TokenIdOf {
+            #[allow(dead_code)]
+            pub fn encode_call(name: Vec<u8>) -> Vec<u8> {
+                <TokenIdOf as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for TokenIdOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 36, 84, 111, 107, 101, 110, 73, 100, 79, 102,
+            ];
+            type Params = Vec<u8>;
+            type Reply = Result<U256, super::RegistrarError>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ServiceEvents {
+            CommitSubmitted {
+                commitment: [u8; 32],
+                timestamp: u64,
+            },
+            NameRegistered {
+                name: Vec<u8>,
+                owner: ActorId,
+                expires: u64,
+                cost: u128,
+            },
+            NameRenewed {
+                name: Vec<u8>,
+                expires: u64,
+                cost: u128,
+            },
+            PriceTiersSet {
+                tiers: PriceTiers,
+            },
+            CommitAgesSet {
+                min: u64,
+                max: u64,
+            },
+            GracePeriodSet {
+                grace: u64,
+            },
+            NamesReserved {
+                labels: Vec<Vec<u8>>,
+            },
+            Withdrawn {
+                to: ActorId,
+                amount: u128,
+            },
+            PayeesSet {
+                payees: Vec<(ActorId, u16)>,
+            },
+            Transfer {
+                from: ActorId,
+                to: ActorId,
+                token_id: U256,
+            },
+            Approval {
+                owner: ActorId,
+                approved: ActorId,
+                token_id: U256,
+            },
+            ApprovalForAll {
+                owner: ActorId,
+                operator: ActorId,
+                approved: bool,
+            },
+            NameReclaimed {
+                token_id: U256,
+                owner: ActorId,
+            },
+            ConfusableAllowed {
+                name: Vec<u8>,
+                allowed: bool,
+            },
+            ExpiryPremiumSet {
+                premium: ExpiryPremium,
+            },
+            AuctionStarted {
+                labelhash: U256,
+                reveal_at: u64,
+                ends_at: u64,
+            },
+            BidSealed {
+                hash: [u8; 32],
+                bidder: ActorId,
+                deposit: u128,
+            },
+            BidRevealed {
+                name: Vec<u8>,
+                bidder: ActorId,
+                value: u128,
+                accepted: bool,
+            },
+            AuctionFinalized {
+                name: Vec<u8>,
+                winner: Option<ActorId>,
+                price: u128,
+            },
+            AuctionPeriodsSet {
+                bidding: u64,
+                reveal: u64,
+            },
+            BidReclaimed {
+                hash: [u8; 32],
+                bidder: ActorId,
+                deposit: u128,
+            },
+        }
+        impl EventIo for ServiceEvents {
+            const ROUTE: &'static [u8] = &[28, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[
+                    60, 67, 111, 109, 109, 105, 116, 83, 117, 98, 109, 105, 116, 116, 101, 100,
+                ],
+                &[
+                    56, 78, 97, 109, 101, 82, 101, 103, 105, 115, 116, 101, 114, 101, 100,
+                ],
+                &[44, 78, 97, 109, 101, 82, 101, 110, 101, 119, 101, 100],
+                &[
+                    52, 80, 114, 105, 99, 101, 84, 105, 101, 114, 115, 83, 101, 116,
+                ],
+                &[
+                    52, 67, 111, 109, 109, 105, 116, 65, 103, 101, 115, 83, 101, 116,
+                ],
+                &[
+                    56, 71, 114, 97, 99, 101, 80, 101, 114, 105, 111, 100, 83, 101, 116,
+                ],
+                &[
+                    52, 78, 97, 109, 101, 115, 82, 101, 115, 101, 114, 118, 101, 100,
+                ],
+                &[36, 87, 105, 116, 104, 100, 114, 97, 119, 110],
+                &[36, 80, 97, 121, 101, 101, 115, 83, 101, 116],
+                &[32, 84, 114, 97, 110, 115, 102, 101, 114],
+                &[32, 65, 112, 112, 114, 111, 118, 97, 108],
+                &[
+                    56, 65, 112, 112, 114, 111, 118, 97, 108, 70, 111, 114, 65, 108, 108,
+                ],
+                &[
+                    52, 78, 97, 109, 101, 82, 101, 99, 108, 97, 105, 109, 101, 100,
+                ],
+                &[
+                    68, 67, 111, 110, 102, 117, 115, 97, 98, 108, 101, 65, 108, 108, 111, 119, 101,
+                    100,
+                ],
+                &[
+                    64, 69, 120, 112, 105, 114, 121, 80, 114, 101, 109, 105, 117, 109, 83, 101, 116,
+                ],
+                &[
+                    56, 65, 117, 99, 116, 105, 111, 110, 83, 116, 97, 114, 116, 101, 100,
+                ],
+                &[36, 66, 105, 100, 83, 101, 97, 108, 101, 100],
+                &[44, 66, 105, 100, 82, 101, 118, 101, 97, 108, 101, 100],
+                &[
+                    64, 65, 117, 99, 116, 105, 111, 110, 70, 105, 110, 97, 108, 105, 122, 101, 100,
+                ],
+                &[
+                    68, 65, 117, 99, 116, 105, 111, 110, 80, 101, 114, 105, 111, 100, 115, 83, 101,
+                    116,
+                ],
+                &[48, 66, 105, 100, 82, 101, 99, 108, 97, 105, 109, 101, 100],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<ServiceEvents> {
+            RemotingListener::<_, ServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitRegistrar {
+    pub registry: ActorId,
+    pub tld_node: U256,
+    /// Must match the registry's `hash_scheme`, as labels are hashed by the registrar.
+    pub hash_scheme: HashScheme,
+    pub controller: ActorId,
+    pub price_tiers: PriceTiers,
+    pub min_commit_age: u64,
+    pub max_commit_age: u64,
+    pub grace_period: u64,
+}
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+/// Admin-set pricing table
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PriceTiers {
+    /// Price per year of a label by its length in Unicode characters: 1, 2, 3, 4, and 5
+    /// or more. The last tier is the base price; shorter labels pay the difference as a
+    /// premium.
+    pub per_year: [u128; 5],
+    /// Discount, in basis points, on registrations and renewals of two years or more
+    pub multi_year_discount: u16,
+}
+/// Temporary premium on names that just became available again after expiring, so that
+/// they are not released to whoever registers first at the normal price. It starts at
+/// `start` once the grace period ends, halves every `half_life` and reaches zero after
+/// `window`. Disabled while `start` or `half_life` is zero.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct ExpiryPremium {
+    pub start: u128,
+    pub half_life: u64,
+    pub window: u64,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RegistrarError {
+    NotController,
+    NameTooLong,
+    NameReserved,
+    NameNotAvailable,
+    NameNotRenewable,
+    AddrRequiresResolver,
+    CommitmentAlreadyExists,
+    TooManyCommitments,
+    NoValidCommitment,
+    CommitmentTooNew,
+    CommitmentExpired,
+    TooManyLabels,
+    Overflow,
+    RegistryCallFailed,
+    InsufficientPayment {
+        price: u128,
+        paid: u128,
+    },
+    InsufficientBalance {
+        requested: u128,
+        available: u128,
+    },
+    TooManyPayees,
+    InvalidShares {
+        total: u32,
+    },
+    NothingToRelease,
+    BelowExistentialDeposit {
+        amount: u128,
+        existential_deposit: u128,
+    },
+    InsufficientProgramBalance {
+        requested: u128,
+        available: u128,
+    },
+    TransferFailed,
+    TokenNotFound,
+    NotTokenOwnerOrApproved,
+    TransferToZeroAddress,
+    InvalidLabel(LabelError),
+    ConfusableName {
+        with: Vec<u8>,
+    },
+    InvalidDiscount {
+        discount: u16,
+    },
+    NameInAuction,
+    AuctionAlreadyStarted,
+    AuctionNotFound,
+    NotInRevealPeriod,
+    AuctionNotEnded,
+    NoValidBid,
+    DepositTooLow {
+        minimum: u128,
+    },
+    TooManySealedBids,
+    BidNotReclaimable,
+}
+/// Why a label was rejected.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LabelError {
+    /// The label is not valid UTF-8.
+    InvalidUtf8,
+    /// The label has fewer than `MIN_LABEL_CHARS` characters.
+    TooShort,
+    /// The label contains a dot, or a character normalizing to one.
+    ContainsDot,
+    /// The label contains a code point that cannot appear in a name.
+    DisallowedCodePoint(u32),
+}
+/// Sealed-bid (Vickrey) auction of a label, known only by its labelhash until bids are
+/// revealed. The highest bidder wins and pays the second-highest bid.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Auction {
+    /// End of bidding: bids sealed later are refused at reveal
+    pub reveal_at: u64,
+    /// End of the reveal period, after which the auction can be finalized
+    pub ends_at: u64,
+    /// Highest valid bid revealed so far: bidder, value and deposit
+    pub highest: Option<(ActorId, u128, u128)>,
+    /// Second-highest valid bid revealed so far
+    pub second: u128,
+}
+/// Price of a registration or renewal: `base + premium - discount + expiry_premium`
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PriceBreakdown {
+    /// Base tier price for the duration
+    pub base: u128,
+    /// Surcharge of the label's tier over the base tier
+    pub premium: u128,
+    /// Multi-year discount on `base + premium`
+    pub discount: u128,
+    /// Decaying premium of a recently expired name, see `ExpiryPremium`
+    pub expiry_premium: u128,
+}
+/// Registrar settings with one page of registered names; commitments are paged separately
+/// by `commits_page`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct IoRegistrarState {
+    pub registry: ActorId,
+    pub tld_node: U256,
+    pub hash_scheme: HashScheme,
+    /// Registered names and their expiries, in name order
+    pub expires: Vec<(Vec<u8>, u64)>,
+    /// Cursor of the next page of `expires`, if there are more
+    pub next_cursor: Option<Vec<u8>>,
+    pub reserved: Vec<Vec<u8>>,
+    pub price_tiers: PriceTiers,
+    pub expiry_premium: ExpiryPremium,
+    pub min_commit_age: u64,
+    pub max_commit_age: u64,
+    pub grace_period: u64,
+    pub controller: ActorId,
+    pub balance: u128,
+    pub payees: Vec<(ActorId, u16)>,
+    pub bidding_period: u64,
+    pub reveal_period: u64,
+}
+/// Bid sealed by `bidder` at `sealed_at`, not revealed yet
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SealedBid {
+    pub bidder: ActorId,
+    pub deposit: u128,
+    pub sealed_at: u64,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, init: InitRegistrar) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn approve(
+            &mut self,
+            approved: ActorId,
+            token_id: U256,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn commit(
+            &mut self,
+            commitment: [u8; 32],
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn finalize_auction(
+            &mut self,
+            name: Vec<u8>,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn reclaim(
+            &mut self,
+            token_id: U256,
+            owner: ActorId,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn reclaim_bid(
+            &mut self,
+            hash: [u8; 32],
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn register(
+            &mut self,
+            name: Vec<u8>,
+            owner: ActorId,
+            duration: u64,
+            secret: [u8; 32],
+            salt: [u8; 32],
+            resolver: Option<ActorId>,
+            addr: Option<ActorId>,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn release(
+            &mut self,
+            payee: ActorId,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn renew(
+            &mut self,
+            name: Vec<u8>,
+            duration: u64,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn reserve_names(
+            &mut self,
+            labels: Vec<Vec<u8>>,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn reveal_bid(
+            &mut self,
+            name: Vec<u8>,
+            value: u128,
+            salt: [u8; 32],
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn seal_bid(
+            &mut self,
+            hash: [u8; 32],
+            deposit: u128,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_approval_for_all(
+            &mut self,
+            operator: ActorId,
+            approved: bool,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_auction_periods(
+            &mut self,
+            bidding: u64,
+            reveal: u64,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_commit_ages(
+            &mut self,
+            min: u64,
+            max: u64,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_confusable_allowed(
+            &mut self,
+            name: Vec<u8>,
+            allowed: bool,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_expiry_premium(
+            &mut self,
+            premium: ExpiryPremium,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_grace_period(
+            &mut self,
+            grace: u64,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_payees(
+            &mut self,
+            payees: Vec<(ActorId, u16)>,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn set_price_tiers(
+            &mut self,
+            tiers: PriceTiers,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn start_auction(
+            &mut self,
+            labelhash: U256,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            token_id: U256,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn withdraw(
+            &mut self,
+            to: ActorId,
+            amount: u128,
+        ) -> impl Call<Output = Result<RegistrarEvent, RegistrarError>, Args = Self::Args>;
+        fn auction_of(
+            &self,
+            labelhash: U256,
+        ) -> impl Query<Output = Option<Auction>, Args = Self::Args>;
+        fn available(
+            &self,
+            name: Vec<u8>,
+        ) -> impl Query<Output = Result<bool, RegistrarError>, Args = Self::Args>;
+        fn balance_of(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
+        fn bid_hash(
+            &self,
+            name: Vec<u8>,
+            bidder: ActorId,
+            value: u128,
+            salt: [u8; 32],
+        ) -> impl Query<Output = Result<[u8; 32], RegistrarError>, Args = Self::Args>;
+        fn commitment_version(&self) -> impl Query<Output = u8, Args = Self::Args>;
+        fn commits_page(
+            &self,
+            cursor: Option<[u8; 32]>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<([u8; 32], u64)>, Option<[u8; 32]>), Args = Self::Args>;
+        fn confusable_with(
+            &self,
+            name: Vec<u8>,
+        ) -> impl Query<Output = Result<Option<Vec<u8>>, RegistrarError>, Args = Self::Args>;
+        fn expiries_page(
+            &self,
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>), Args = Self::Args>;
+        fn expiry_of(&self, name: Vec<u8>) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+        fn get_approved(
+            &self,
+            token_id: U256,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn is_approved_for_all(
+            &self,
+            owner: ActorId,
+            operator: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn labels_of(
+            &self,
+            owner: ActorId,
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<(Vec<u8>, u64)>, Option<Vec<u8>>), Args = Self::Args>;
+        fn make_commitment(
+            &self,
+            name: Vec<u8>,
+            owner: ActorId,
+            secret: [u8; 32],
+            salt: [u8; 32],
+            duration: u64,
+            resolver: Option<ActorId>,
+        ) -> impl Query<Output = Result<[u8; 32], RegistrarError>, Args = Self::Args>;
+        fn min_bid_deposit(&self) -> impl Query<Output = u128, Args = Self::Args>;
+        fn owner_of_token(
+            &self,
+            token_id: U256,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn payee_balance(&self, payee: ActorId) -> impl Query<Output = u128, Args = Self::Args>;
+        fn payees(&self) -> impl Query<Output = Vec<(ActorId, u16)>, Args = Self::Args>;
+        fn price(
+            &self,
+            name: Vec<u8>,
+            duration: u64,
+        ) -> impl Query<Output = Result<PriceBreakdown, RegistrarError>, Args = Self::Args>;
+        fn query_state(
+            &self,
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> impl Query<Output = IoRegistrarState, Args = Self::Args>;
+        fn sealed_bid(
+            &self,
+            hash: [u8; 32],
+        ) -> impl Query<Output = Option<SealedBid>, Args = Self::Args>;
+        fn token_id_of(
+            &self,
+            name: Vec<u8>,
+        ) -> impl Query<Output = Result<U256, RegistrarError>, Args = Self::Args>;
+    }
+}
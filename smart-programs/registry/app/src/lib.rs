@@ -15,7 +15,7 @@ impl Program {
         Self
     }
 
-    #[route("Service")]
+    #[export(route = "Service")]
     pub fn service(&self) -> Service {
         Service::new()
     }
@@ -1,16 +1,18 @@
-
-#![no_std]
 #![allow(static_mut_refs)]
 
 use sails_rs::{
     prelude::*,
-    gstd::msg,
-    collections::HashMap,
+    gstd::{exec, msg},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
 };
-use sails_rs::collections::HashSet;
 
 /// Type representing a node (e.g. namehash for VNS)
-pub type Node = U256;
+pub use vns_common::Node;
+pub use vns_common::HashScheme;
+use vns_common::pagination;
+
+/// Longest plaintext label the preimage store accepts.
+const MAX_LABEL_LENGTH: usize = 256;
 
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
@@ -23,17 +25,49 @@ pub enum RegistryEvent {
     NewSubnodeOwner { parent: Node, label: U256, subnode: Node, owner: ActorId },
     ControllerAdded(ActorId),
     ControllerRemoved(ActorId),
+    NewExpiry { node: Node, expiry: u64 },
+    PreimageAdded { labelhash: U256, label: String },
+    RootNameSet { node: Node, name: String },
+    ApprovalForAll { owner: ActorId, operator: ActorId, approved: bool },
 }
 
-#[derive(Debug, Encode, Decode, TypeInfo, Clone, Default)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
+pub enum RegistryError {
+    NotOwnerOrController,
+    NotParentOwnerOrController,
+    NotAdmin,
+    /// Plaintext labels must be non-empty, dot-free and at most 256 bytes
+    InvalidLabel,
+    /// The parent has no subnode with this label
+    UnknownLabel,
+}
+
+#[derive(Debug, Default)]
 pub struct RegistryState {
     pub admin: ActorId,
-    pub owners: HashMap<Node, ActorId>,
+    pub owners: BTreeMap<Node, ActorId>,
     pub resolvers: HashMap<Node, ActorId>,
     pub ttls: HashMap<Node, u64>,
     pub controllers: HashSet<ActorId>,
+    pub hash_scheme: HashScheme,
+    /// Timestamps after which nodes are treated as unowned, e.g. a second-level name's
+    /// `expires + grace_period` as set by the registrar.
+    pub expiries: BTreeMap<Node, u64>,
+    /// Nodes held by each owner, kept in step with `owners`.
+    pub nodes_by_owner: BTreeMap<ActorId, BTreeSet<Node>>,
+    /// Subnodes created under each parent, by label hash.
+    pub children: BTreeMap<Node, BTreeMap<U256, Node>>,
+    /// Parent and label hash of each subnode.
+    pub parents: HashMap<Node, (Node, U256)>,
+    /// Plaintext of label hashes, where known.
+    pub preimages: HashMap<U256, String>,
+    /// Full names of nodes with no parent in the registry, such as the root node it was
+    /// initialized with.
+    pub root_names: HashMap<Node, String>,
+    /// (owner, operator) pairs where the operator may manage all of the owner's nodes.
+    pub operators: HashSet<(ActorId, ActorId)>,
 }
 
 #[derive(Debug, Encode, Decode, TypeInfo, Clone)]
@@ -42,9 +76,11 @@ pub struct RegistryState {
 pub struct IoRegistryState {
     pub admin: ActorId,
     pub controllers: Vec<ActorId>,
+    pub hash_scheme: HashScheme,
+    /// Node owners, in node order, as stored (see `owners_page`)
     pub owners: Vec<(Node, ActorId)>,
-    pub resolvers: Vec<(Node, ActorId)>,
-    pub ttls: Vec<(Node, u64)>,
+    /// Cursor of the next page of `owners`, if there are more
+    pub next_cursor: Option<Node>,
 }
 
 
@@ -55,6 +91,8 @@ pub struct InitRegistry {
     pub admin: ActorId,
     pub root_node: Node,
     pub root_owner: ActorId,
+    /// Subnode derivation used for the lifetime of the registry.
+    pub hash_scheme: HashScheme,
 }
 
 static mut REGISTRY_STATE: Option<RegistryState> = None;
@@ -70,40 +108,103 @@ impl RegistryState {
         debug_assert!(s.is_some(), "Registry state not initialized");
         unsafe { s.unwrap_unchecked() }
     }
+    /// Owner of a node, unless its expiry has passed.
+    pub fn live_owner(&self, node: &Node) -> Option<ActorId> {
+        let expired = self.expiries.get(node).is_some_and(|expiry| exec::block_timestamp() > *expiry);
+        if expired { None } else { self.owners.get(node).copied() }
+    }
+    /// Set the owner of a node, moving it between owners in `nodes_by_owner`.
+    pub fn set_node_owner(&mut self, node: Node, owner: ActorId) {
+        if let Some(previous) = self.owners.insert(node, owner) {
+            if let Some(nodes) = self.nodes_by_owner.get_mut(&previous) {
+                nodes.remove(&node);
+                if nodes.is_empty() {
+                    self.nodes_by_owner.remove(&previous);
+                }
+            }
+        }
+        self.nodes_by_owner.entry(owner).or_default().insert(node);
+    }
+    /// Record `subnode` as the child `label` of `parent`.
+    pub fn add_child(&mut self, parent: Node, label: U256, subnode: Node) {
+        self.children.entry(parent).or_default().insert(label, subnode);
+        self.parents.insert(subnode, (parent, label));
+    }
+    /// Label hash of a plaintext label, if it is one the preimage store accepts.
+    pub fn labelhash_of(&self, label: &str) -> Result<U256, RegistryError> {
+        if label.is_empty() || label.len() > MAX_LABEL_LENGTH || label.contains('.') {
+            return Err(RegistryError::InvalidLabel);
+        }
+        Ok(self.hash_scheme.labelhash(label.as_bytes()))
+    }
+    /// Store the plaintext of a label, returning its label hash.
+    pub fn add_preimage(&mut self, label: &str) -> Result<U256, RegistryError> {
+        let labelhash = self.labelhash_of(label)?;
+        self.preimages.insert(labelhash, label.into());
+        Ok(labelhash)
+    }
+    /// Full dotted name of a node, if the plaintext of every label up to a root is known.
+    pub fn name_of_node(&self, node: &Node) -> Option<String> {
+        let mut labels = Vec::new();
+        let mut node = *node;
+        loop {
+            if let Some(root_name) = self.root_names.get(&node) {
+                labels.push(root_name.clone());
+                break;
+            }
+            if node == vns_common::root_node() {
+                break;
+            }
+            let (parent, labelhash) = self.parents.get(&node)?;
+            labels.push(self.preimages.get(labelhash)?.clone());
+            node = *parent;
+        }
+        Some(labels.join("."))
+    }
     pub fn is_owner_or_controller(&self, node: &Node, actor: &ActorId) -> bool {
-        self.owners.get(node).map_or(false, |x| x == actor) || self.controllers.contains(actor)
+        self.live_owner(node).is_some_and(|x| &x == actor || self.operators.contains(&(x, *actor)))
+            || self.controllers.contains(actor)
     }
     pub fn is_admin(&self, actor: &ActorId) -> bool {
         &self.admin == actor
     }
-}
-
-impl From<RegistryState> for IoRegistryState {
-    fn from(s: RegistryState) -> Self {
-        Self {
-            admin: s.admin,
-            controllers: s.controllers.iter().cloned().collect(),
-            owners: s.owners.iter().map(|(k, v)| (*k, *v)).collect(),
-            resolvers: s.resolvers.iter().map(|(k, v)| (*k, *v)).collect(),
-            ttls: s.ttls.iter().map(|(k, v)| (*k, *v)).collect(),
+    pub fn io_state(&self, cursor: Option<&Node>, limit: u32) -> IoRegistryState {
+        let (owners, next_cursor) = pagination::page(&self.owners, cursor, limit);
+        IoRegistryState {
+            admin: self.admin,
+            controllers: self.controllers.iter().cloned().collect(),
+            hash_scheme: self.hash_scheme,
+            owners,
+            next_cursor,
         }
     }
 }
 
+
 #[derive(Default)]
 pub struct Service;
 
 impl Service {
     pub fn seed(init: InitRegistry) {
         unsafe {
-            let mut controllers = HashSet::new();
-            REGISTRY_STATE = Some(RegistryState {
+            let controllers = HashSet::new();
+            let mut state = RegistryState {
                 admin: init.admin,
-                owners: [(init.root_node, init.root_owner)].into(),
+                owners: BTreeMap::new(),
                 resolvers: HashMap::new(),
                 ttls: HashMap::new(),
                 controllers,
-            });
+                hash_scheme: init.hash_scheme,
+                expiries: BTreeMap::new(),
+                nodes_by_owner: BTreeMap::new(),
+                children: BTreeMap::new(),
+                parents: HashMap::new(),
+                preimages: HashMap::new(),
+                root_names: HashMap::new(),
+                operators: HashSet::new(),
+            };
+            state.set_node_owner(init.root_node, init.root_owner);
+            REGISTRY_STATE = Some(state);
         }
     }
 }
@@ -113,91 +214,185 @@ impl Service {
     pub fn new() -> Self { Self }
 
     /// Set the owner for a node. Only owner or controller may call.
-    pub fn set_owner(&mut self, node: Node, new_owner: ActorId) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn set_owner(&mut self, node: Node, new_owner: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_owner_or_controller(&node, &caller) {
-            panic!("Not owner or controller");
+            return Err(RegistryError::NotOwnerOrController);
         }
-        s.owners.insert(node, new_owner);
+        s.set_node_owner(node, new_owner);
         self.emit_event(RegistryEvent::NewOwner { node, owner: new_owner })
             .expect("Event failed");
-        RegistryEvent::NewOwner { node, owner: new_owner }
+        Ok(RegistryEvent::NewOwner { node, owner: new_owner })
     }
 
     /// Set the resolver for a node. Only owner or controller may call.
-    pub fn set_resolver(&mut self, node: Node, resolver: ActorId) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn set_resolver(&mut self, node: Node, resolver: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_owner_or_controller(&node, &caller) {
-            panic!("Not owner or controller");
+            return Err(RegistryError::NotOwnerOrController);
         }
         s.resolvers.insert(node, resolver);
         self.emit_event(RegistryEvent::NewResolver { node, resolver })
             .expect("Event failed");
-        RegistryEvent::NewResolver { node, resolver }
+        Ok(RegistryEvent::NewResolver { node, resolver })
     }
 
     /// Set the TTL for a node. Only owner or controller may call.
-    pub fn set_ttl(&mut self, node: Node, ttl: u64) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn set_ttl(&mut self, node: Node, ttl: u64) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_owner_or_controller(&node, &caller) {
-            panic!("Not owner or controller");
+            return Err(RegistryError::NotOwnerOrController);
         }
         s.ttls.insert(node, ttl);
         self.emit_event(RegistryEvent::NewTTL { node, ttl })
             .expect("Event failed");
-        RegistryEvent::NewTTL { node, ttl }
+        Ok(RegistryEvent::NewTTL { node, ttl })
     }
 
     /// Set the owner for a subnode by specifying parent & label. Only parent owner or controller may call.
-    pub fn set_subnode_owner(&mut self, parent: Node, label: U256, new_owner: ActorId) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn set_subnode_owner(&mut self, parent: Node, label: U256, new_owner: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_owner_or_controller(&parent, &caller) {
-            panic!("Not parent owner or controller");
-        }
-        // Derive new node: hash(parent, label)
-        let mut node_bytes = [0u8; 64];
-        parent.to_little_endian(&mut node_bytes[0..32]);
-        label.to_little_endian(&mut node_bytes[32..64]);
-        let subnode = sails_rs::prelude::hash_bytes::<Node>(&node_bytes);
-        s.owners.insert(subnode, new_owner);
+            return Err(RegistryError::NotParentOwnerOrController);
+        }
+        let subnode = s.hash_scheme.subnode(parent, label);
+        s.set_node_owner(subnode, new_owner);
+        s.add_child(parent, label, subnode);
         self.emit_event(RegistryEvent::NewSubnodeOwner { parent, label, subnode, owner: new_owner })
             .expect("Event failed");
-        RegistryEvent::NewSubnodeOwner { parent, label, subnode, owner: new_owner }
+        Ok(RegistryEvent::NewSubnodeOwner { parent, label, subnode, owner: new_owner })
+    }
+
+    /// Set the owner for a subnode given its plaintext label, which is kept so the node can
+    /// be named by `name_of_node`. Only parent owner or controller may call.
+    pub fn set_subnode_owner_with_label(&mut self, parent: Node, label: String, new_owner: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
+        let caller = msg::source();
+        if !s.is_owner_or_controller(&parent, &caller) {
+            return Err(RegistryError::NotParentOwnerOrController);
+        }
+        let labelhash = s.add_preimage(&label)?;
+        let subnode = s.hash_scheme.subnode(parent, labelhash);
+        s.set_node_owner(subnode, new_owner);
+        s.add_child(parent, labelhash, subnode);
+        self.emit_event(RegistryEvent::PreimageAdded { labelhash, label })
+            .expect("Event failed");
+        self.emit_event(RegistryEvent::NewSubnodeOwner { parent, label: labelhash, subnode, owner: new_owner })
+            .expect("Event failed");
+        Ok(RegistryEvent::NewSubnodeOwner { parent, label: labelhash, subnode, owner: new_owner })
+    }
+
+    /// Store the plaintext of the label of an existing subnode of `parent`. Anyone may call:
+    /// the label is hashed here, so only true preimages are stored, and only for labels in
+    /// use, so the store grows no faster than the registry itself.
+    pub fn add_preimage(&mut self, parent: Node, label: String) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
+        let labelhash = s.labelhash_of(&label)?;
+        if !s.children.get(&parent).is_some_and(|children| children.contains_key(&labelhash)) {
+            return Err(RegistryError::UnknownLabel);
+        }
+        s.add_preimage(&label)?;
+        self.emit_event(RegistryEvent::PreimageAdded { labelhash, label: label.clone() })
+            .expect("Event failed");
+        Ok(RegistryEvent::PreimageAdded { labelhash, label })
+    }
+
+    /// Name a node that has no parent in the registry, such as the root node it was
+    /// initialized with, by its full dotted name. Only admin may call.
+    pub fn set_root_name(&mut self, name: String) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
+        let caller = msg::source();
+        if !s.is_admin(&caller) {
+            return Err(RegistryError::NotAdmin);
+        }
+        let node = s.hash_scheme.namehash(&name);
+        s.root_names.insert(node, name.clone());
+        self.emit_event(RegistryEvent::RootNameSet { node, name: name.clone() })
+            .expect("Event failed");
+        Ok(RegistryEvent::RootNameSet { node, name })
+    }
+
+    /// Set the owner for a subnode together with the time its ownership lapses, as the
+    /// registrar does when a name is registered. Only parent owner or controller may call.
+    pub fn set_subnode_owner_with_expiry(&mut self, parent: Node, label: U256, new_owner: ActorId, expiry: u64) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
+        let caller = msg::source();
+        if !s.is_owner_or_controller(&parent, &caller) {
+            return Err(RegistryError::NotParentOwnerOrController);
+        }
+        let subnode = s.hash_scheme.subnode(parent, label);
+        s.set_node_owner(subnode, new_owner);
+        s.add_child(parent, label, subnode);
+        s.expiries.insert(subnode, expiry);
+        self.emit_event(RegistryEvent::NewExpiry { node: subnode, expiry })
+            .expect("Event failed");
+        self.emit_event(RegistryEvent::NewSubnodeOwner { parent, label, subnode, owner: new_owner })
+            .expect("Event failed");
+        Ok(RegistryEvent::NewSubnodeOwner { parent, label, subnode, owner: new_owner })
+    }
+
+    /// Set the time a subnode's ownership lapses, e.g. on renewal. Only parent owner or controller may call.
+    pub fn set_subnode_expiry(&mut self, parent: Node, label: U256, expiry: u64) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
+        let caller = msg::source();
+        if !s.is_owner_or_controller(&parent, &caller) {
+            return Err(RegistryError::NotParentOwnerOrController);
+        }
+        let node = s.hash_scheme.subnode(parent, label);
+        s.expiries.insert(node, expiry);
+        self.emit_event(RegistryEvent::NewExpiry { node, expiry })
+            .expect("Event failed");
+        Ok(RegistryEvent::NewExpiry { node, expiry })
+    }
+
+    /// Allow or disallow `operator` to manage all of the caller's nodes, as their owner
+    /// would.
+    pub fn set_approval_for_all(&mut self, operator: ActorId, approved: bool) -> Result<RegistryEvent, RegistryError> {
+        let owner = msg::source();
+        let s = RegistryState::state_mut();
+        if approved {
+            s.operators.insert((owner, operator));
+        } else {
+            s.operators.remove(&(owner, operator));
+        }
+        self.emit_event(RegistryEvent::ApprovalForAll { owner, operator, approved })
+            .expect("Event failed");
+        Ok(RegistryEvent::ApprovalForAll { owner, operator, approved })
     }
 
     /// Add a controller. Only admin may call.
-    pub fn add_controller(&mut self, controller: ActorId) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn add_controller(&mut self, controller: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not admin");
+            return Err(RegistryError::NotAdmin);
         }
         s.controllers.insert(controller);
         self.emit_event(RegistryEvent::ControllerAdded(controller))
             .expect("Event failed");
-        RegistryEvent::ControllerAdded(controller)
+        Ok(RegistryEvent::ControllerAdded(controller))
     }
 
     /// Remove a controller. Only admin may call.
-    pub fn remove_controller(&mut self, controller: ActorId) -> RegistryEvent {
-        let mut s = RegistryState::state_mut();
+    pub fn remove_controller(&mut self, controller: ActorId) -> Result<RegistryEvent, RegistryError> {
+        let s = RegistryState::state_mut();
         let caller = msg::source();
         if !s.is_admin(&caller) {
-            panic!("Not admin");
+            return Err(RegistryError::NotAdmin);
         }
         s.controllers.remove(&controller);
         self.emit_event(RegistryEvent::ControllerRemoved(controller))
             .expect("Event failed");
-        RegistryEvent::ControllerRemoved(controller)
+        Ok(RegistryEvent::ControllerRemoved(controller))
     }
 
-    /// OWNER QUERY: Return the owner for a node.
+    /// OWNER QUERY: Return the owner for a node, or `None` once its expiry has passed.
     pub fn owner_of(&self, node: Node) -> Option<ActorId> {
-        RegistryState::state_ref().owners.get(&node).copied()
+        RegistryState::state_ref().live_owner(&node)
     }
 
     /// RESOLVER QUERY: Return the resolver for a node.
@@ -210,8 +405,63 @@ impl Service {
         RegistryState::state_ref().ttls.get(&node).copied()
     }
 
-    /// Query the whole on-chain registry state.
-    pub fn query_state(&self) -> IoRegistryState {
-        RegistryState::state_ref().clone().into()
+    /// EXPIRY QUERY: Return the time a node's ownership lapses, if it has one.
+    pub fn expiry_of(&self, node: Node) -> Option<u64> {
+        RegistryState::state_ref().expiries.get(&node).copied()
+    }
+
+    /// OPERATOR QUERY: Return whether `operator` may manage all of `owner`'s nodes.
+    pub fn is_approved_for_all(&self, owner: ActorId, operator: ActorId) -> bool {
+        RegistryState::state_ref().operators.contains(&(owner, operator))
+    }
+
+    /// SCHEME QUERY: Return how this registry derives subnodes.
+    pub fn hash_scheme(&self) -> HashScheme {
+        RegistryState::state_ref().hash_scheme
+    }
+
+    /// Query a page of node owners, in node order, as stored: owners whose expiry has
+    /// passed are included (see `expiries_page`). Pass the returned cursor to get the next
+    /// page; `None` means there are no more.
+    pub fn owners_page(&self, cursor: Option<Node>, limit: u32) -> (Vec<(Node, ActorId)>, Option<Node>) {
+        pagination::page(&RegistryState::state_ref().owners, cursor.as_ref(), limit)
+    }
+
+    /// Query a page of the nodes held by `owner`, in node order, with the cursor of the next
+    /// page. Nodes whose expiry has passed are included until they change hands.
+    pub fn nodes_of(&self, owner: ActorId, cursor: Option<Node>, limit: u32) -> (Vec<Node>, Option<Node>) {
+        match RegistryState::state_ref().nodes_by_owner.get(&owner) {
+            Some(nodes) => pagination::page_set(nodes, cursor.as_ref(), limit),
+            None => (Vec::new(), None),
+        }
+    }
+
+    /// Query a page of the subnodes created under `parent`, as (label hash, subnode) pairs in
+    /// label hash order, with the cursor of the next page.
+    pub fn children_of(&self, parent: Node, cursor: Option<U256>, limit: u32) -> (Vec<(U256, Node)>, Option<U256>) {
+        match RegistryState::state_ref().children.get(&parent) {
+            Some(children) => pagination::page(children, cursor.as_ref(), limit),
+            None => (Vec::new(), None),
+        }
+    }
+
+    /// Query the parent and label hash of a subnode; `None` for top-level nodes.
+    pub fn parent_of(&self, node: Node) -> Option<(Node, U256)> {
+        RegistryState::state_ref().parents.get(&node).copied()
+    }
+
+    /// Query the full dotted name of a node, if the plaintext of all its labels is known.
+    pub fn name_of_node(&self, node: Node) -> Option<String> {
+        RegistryState::state_ref().name_of_node(&node)
+    }
+
+    /// Query a page of node expiries, in node order, with the cursor of the next page.
+    pub fn expiries_page(&self, cursor: Option<Node>, limit: u32) -> (Vec<(Node, u64)>, Option<Node>) {
+        pagination::page(&RegistryState::state_ref().expiries, cursor.as_ref(), limit)
+    }
+
+    /// Query registry settings with a page of node owners, starting after `cursor`
+    pub fn query_state(&self, cursor: Option<Node>, limit: u32) -> IoRegistryState {
+        RegistryState::state_ref().io_state(cursor.as_ref(), limit)
     }
 }
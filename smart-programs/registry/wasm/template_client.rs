@@ -0,0 +1,898 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    fn new(&self, init: InitRegistry) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), init)
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(init: super::InitRegistry) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&init)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = super::InitRegistry;
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Add a controller. Only admin may call.
+    fn add_controller(
+        &mut self,
+        controller: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::AddController>::new(self.remoting.clone(), controller)
+    }
+    /// Store the plaintext of the label of an existing subnode of `parent`. Anyone may call:
+    /// the label is hashed here, so only true preimages are stored, and only for labels in
+    /// use, so the store grows no faster than the registry itself.
+    fn add_preimage(
+        &mut self,
+        parent: U256,
+        label: String,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::AddPreimage>::new(self.remoting.clone(), (parent, label))
+    }
+    /// Remove a controller. Only admin may call.
+    fn remove_controller(
+        &mut self,
+        controller: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::RemoveController>::new(self.remoting.clone(), controller)
+    }
+    /// Allow or disallow `operator` to manage all of the caller's nodes, as their owner
+    /// would.
+    fn set_approval_for_all(
+        &mut self,
+        operator: ActorId,
+        approved: bool,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetApprovalForAll>::new(
+            self.remoting.clone(),
+            (operator, approved),
+        )
+    }
+    /// Set the owner for a node. Only owner or controller may call.
+    fn set_owner(
+        &mut self,
+        node: U256,
+        new_owner: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetOwner>::new(self.remoting.clone(), (node, new_owner))
+    }
+    /// Set the resolver for a node. Only owner or controller may call.
+    fn set_resolver(
+        &mut self,
+        node: U256,
+        resolver: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetResolver>::new(self.remoting.clone(), (node, resolver))
+    }
+    /// Name a node that has no parent in the registry, such as the root node it was
+    /// initialized with, by its full dotted name. Only admin may call.
+    fn set_root_name(
+        &mut self,
+        name: String,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetRootName>::new(self.remoting.clone(), name)
+    }
+    /// Set the time a subnode's ownership lapses, e.g. on renewal. Only parent owner or controller may call.
+    fn set_subnode_expiry(
+        &mut self,
+        parent: U256,
+        label: U256,
+        expiry: u64,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetSubnodeExpiry>::new(
+            self.remoting.clone(),
+            (parent, label, expiry),
+        )
+    }
+    /// Set the owner for a subnode by specifying parent & label. Only parent owner or controller may call.
+    fn set_subnode_owner(
+        &mut self,
+        parent: U256,
+        label: U256,
+        new_owner: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetSubnodeOwner>::new(
+            self.remoting.clone(),
+            (parent, label, new_owner),
+        )
+    }
+    /// Set the owner for a subnode together with the time its ownership lapses, as the
+    /// registrar does when a name is registered. Only parent owner or controller may call.
+    fn set_subnode_owner_with_expiry(
+        &mut self,
+        parent: U256,
+        label: U256,
+        new_owner: ActorId,
+        expiry: u64,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetSubnodeOwnerWithExpiry>::new(
+            self.remoting.clone(),
+            (parent, label, new_owner, expiry),
+        )
+    }
+    /// Set the owner for a subnode given its plaintext label, which is kept so the node can
+    /// be named by `name_of_node`. Only parent owner or controller may call.
+    fn set_subnode_owner_with_label(
+        &mut self,
+        parent: U256,
+        label: String,
+        new_owner: ActorId,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetSubnodeOwnerWithLabel>::new(
+            self.remoting.clone(),
+            (parent, label, new_owner),
+        )
+    }
+    /// Set the TTL for a node. Only owner or controller may call.
+    fn set_ttl(
+        &mut self,
+        node: U256,
+        ttl: u64,
+    ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetTtl>::new(self.remoting.clone(), (node, ttl))
+    }
+    /// Query a page of the subnodes created under `parent`, as (label hash, subnode) pairs in
+    /// label hash order, with the cursor of the next page.
+    fn children_of(
+        &self,
+        parent: U256,
+        cursor: Option<U256>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<(U256, U256)>, Option<U256>), Args = R::Args> {
+        RemotingAction::<_, service::io::ChildrenOf>::new(
+            self.remoting.clone(),
+            (parent, cursor, limit),
+        )
+    }
+    /// Query a page of node expiries, in node order, with the cursor of the next page.
+    fn expiries_page(
+        &self,
+        cursor: Option<U256>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<(U256, u64)>, Option<U256>), Args = R::Args> {
+        RemotingAction::<_, service::io::ExpiriesPage>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// EXPIRY QUERY: Return the time a node's ownership lapses, if it has one.
+    fn expiry_of(&self, node: U256) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, service::io::ExpiryOf>::new(self.remoting.clone(), node)
+    }
+    /// SCHEME QUERY: Return how this registry derives subnodes.
+    fn hash_scheme(&self) -> impl Query<Output = HashScheme, Args = R::Args> {
+        RemotingAction::<_, service::io::HashScheme>::new(self.remoting.clone(), ())
+    }
+    /// OPERATOR QUERY: Return whether `operator` may manage all of `owner`'s nodes.
+    fn is_approved_for_all(
+        &self,
+        owner: ActorId,
+        operator: ActorId,
+    ) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, service::io::IsApprovedForAll>::new(
+            self.remoting.clone(),
+            (owner, operator),
+        )
+    }
+    /// Query the full dotted name of a node, if the plaintext of all its labels is known.
+    fn name_of_node(&self, node: U256) -> impl Query<Output = Option<String>, Args = R::Args> {
+        RemotingAction::<_, service::io::NameOfNode>::new(self.remoting.clone(), node)
+    }
+    /// Query a page of the nodes held by `owner`, in node order, with the cursor of the next
+    /// page. Nodes whose expiry has passed are included until they change hands.
+    fn nodes_of(
+        &self,
+        owner: ActorId,
+        cursor: Option<U256>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<U256>, Option<U256>), Args = R::Args> {
+        RemotingAction::<_, service::io::NodesOf>::new(
+            self.remoting.clone(),
+            (owner, cursor, limit),
+        )
+    }
+    /// OWNER QUERY: Return the owner for a node, or `None` once its expiry has passed.
+    fn owner_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::OwnerOf>::new(self.remoting.clone(), node)
+    }
+    /// Query a page of node owners, in node order, as stored: owners whose expiry has
+    /// passed are included (see `expiries_page`). Pass the returned cursor to get the next
+    /// page; `None` means there are no more.
+    fn owners_page(
+        &self,
+        cursor: Option<U256>,
+        limit: u32,
+    ) -> impl Query<Output = (Vec<(U256, ActorId)>, Option<U256>), Args = R::Args> {
+        RemotingAction::<_, service::io::OwnersPage>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// Query the parent and label hash of a subnode; `None` for top-level nodes.
+    fn parent_of(&self, node: U256) -> impl Query<Output = Option<(U256, U256)>, Args = R::Args> {
+        RemotingAction::<_, service::io::ParentOf>::new(self.remoting.clone(), node)
+    }
+    /// Query registry settings with a page of node owners, starting after `cursor`
+    fn query_state(
+        &self,
+        cursor: Option<U256>,
+        limit: u32,
+    ) -> impl Query<Output = IoRegistryState, Args = R::Args> {
+        RemotingAction::<_, service::io::QueryState>::new(self.remoting.clone(), (cursor, limit))
+    }
+    /// RESOLVER QUERY: Return the resolver for a node.
+    fn resolver_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::ResolverOf>::new(self.remoting.clone(), node)
+    }
+    /// TTL QUERY: Return the TTL for a node.
+    fn ttl_of(&self, node: U256) -> impl Query<Output = Option<u64>, Args = R::Args> {
+        RemotingAction::<_, service::io::TtlOf>::new(self.remoting.clone(), node)
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AddController(());
+        impl AddController {
+            #[allow(dead_code)]
+            pub fn encode_call(controller: ActorId) -> Vec<u8> {
+                <AddController as ActionIo>::encode_call(&controller)
+            }
+        }
+        impl ActionIo for AddController {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 65, 100, 100, 67, 111, 110, 116, 114, 111,
+                108, 108, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct AddPreimage(());
+        impl AddPreimage {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, label: String) -> Vec<u8> {
+                <AddPreimage as ActionIo>::encode_call(&(parent, label))
+            }
+        }
+        impl ActionIo for AddPreimage {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 65, 100, 100, 80, 114, 101, 105, 109, 97,
+                103, 101,
+            ];
+            type Params = (U256, String);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct RemoveController(());
+        impl RemoveController {
+            #[allow(dead_code)]
+            pub fn encode_call(controller: ActorId) -> Vec<u8> {
+                <RemoveController as ActionIo>::encode_call(&controller)
+            }
+        }
+        impl ActionIo for RemoveController {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 82, 101, 109, 111, 118, 101, 67, 111, 110,
+                116, 114, 111, 108, 108, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetApprovalForAll(());
+        impl SetApprovalForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(operator: ActorId, approved: bool) -> Vec<u8> {
+                <SetApprovalForAll as ActionIo>::encode_call(&(operator, approved))
+            }
+        }
+        impl ActionIo for SetApprovalForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 68, 83, 101, 116, 65, 112, 112, 114, 111, 118,
+                97, 108, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, bool);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetOwner(());
+        impl SetOwner {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, new_owner: ActorId) -> Vec<u8> {
+                <SetOwner as ActionIo>::encode_call(&(node, new_owner))
+            }
+        }
+        impl ActionIo for SetOwner {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101, 116, 79, 119, 110, 101, 114,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetResolver(());
+        impl SetResolver {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, resolver: ActorId) -> Vec<u8> {
+                <SetResolver as ActionIo>::encode_call(&(node, resolver))
+            }
+        }
+        impl ActionIo for SetResolver {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 83, 101, 116, 82, 101, 115, 111, 108, 118,
+                101, 114,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetRootName(());
+        impl SetRootName {
+            #[allow(dead_code)]
+            pub fn encode_call(name: String) -> Vec<u8> {
+                <SetRootName as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for SetRootName {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 44, 83, 101, 116, 82, 111, 111, 116, 78, 97,
+                109, 101,
+            ];
+            type Params = String;
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetSubnodeExpiry(());
+        impl SetSubnodeExpiry {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, label: U256, expiry: u64) -> Vec<u8> {
+                <SetSubnodeExpiry as ActionIo>::encode_call(&(parent, label, expiry))
+            }
+        }
+        impl ActionIo for SetSubnodeExpiry {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 83, 101, 116, 83, 117, 98, 110, 111, 100,
+                101, 69, 120, 112, 105, 114, 121,
+            ];
+            type Params = (U256, U256, u64);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetSubnodeOwner(());
+        impl SetSubnodeOwner {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, label: U256, new_owner: ActorId) -> Vec<u8> {
+                <SetSubnodeOwner as ActionIo>::encode_call(&(parent, label, new_owner))
+            }
+        }
+        impl ActionIo for SetSubnodeOwner {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 60, 83, 101, 116, 83, 117, 98, 110, 111, 100,
+                101, 79, 119, 110, 101, 114,
+            ];
+            type Params = (U256, U256, ActorId);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetSubnodeOwnerWithExpiry(());
+        impl SetSubnodeOwnerWithExpiry {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                parent: U256,
+                label: U256,
+                new_owner: ActorId,
+                expiry: u64,
+            ) -> Vec<u8> {
+                <SetSubnodeOwnerWithExpiry as ActionIo>::encode_call(&(
+                    parent, label, new_owner, expiry,
+                ))
+            }
+        }
+        impl ActionIo for SetSubnodeOwnerWithExpiry {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 100, 83, 101, 116, 83, 117, 98, 110, 111, 100,
+                101, 79, 119, 110, 101, 114, 87, 105, 116, 104, 69, 120, 112, 105, 114, 121,
+            ];
+            type Params = (U256, U256, ActorId, u64);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetSubnodeOwnerWithLabel(());
+        impl SetSubnodeOwnerWithLabel {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, label: String, new_owner: ActorId) -> Vec<u8> {
+                <SetSubnodeOwnerWithLabel as ActionIo>::encode_call(&(parent, label, new_owner))
+            }
+        }
+        impl ActionIo for SetSubnodeOwnerWithLabel {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 96, 83, 101, 116, 83, 117, 98, 110, 111, 100,
+                101, 79, 119, 110, 101, 114, 87, 105, 116, 104, 76, 97, 98, 101, 108,
+            ];
+            type Params = (U256, String, ActorId);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct SetTtl(());
+        impl SetTtl {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, ttl: u64) -> Vec<u8> {
+                <SetTtl as ActionIo>::encode_call(&(node, ttl))
+            }
+        }
+        impl ActionIo for SetTtl {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 83, 101, 116, 84, 116, 108,
+            ];
+            type Params = (U256, u64);
+            type Reply = Result<super::RegistryEvent, super::RegistryError>;
+        }
+        pub struct ChildrenOf(());
+        impl ChildrenOf {
+            #[allow(dead_code)]
+            pub fn encode_call(parent: U256, cursor: Option<U256>, limit: u32) -> Vec<u8> {
+                <ChildrenOf as ActionIo>::encode_call(&(parent, cursor, limit))
+            }
+        }
+        impl ActionIo for ChildrenOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 67, 104, 105, 108, 100, 114, 101, 110, 79,
+                102,
+            ];
+            type Params = (U256, Option<U256>, u32);
+            type Reply = (Vec<(U256, U256)>, Option<U256>);
+        }
+        pub struct ExpiriesPage(());
+        impl ExpiriesPage {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<U256>, limit: u32) -> Vec<u8> {
+                <ExpiriesPage as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for ExpiriesPage {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 48, 69, 120, 112, 105, 114, 105, 101, 115, 80,
+                97, 103, 101,
+            ];
+            type Params = (Option<U256>, u32);
+            type Reply = (Vec<(U256, u64)>, Option<U256>);
+        }
+        pub struct ExpiryOf(());
+        impl ExpiryOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <ExpiryOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for ExpiryOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 69, 120, 112, 105, 114, 121, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<u64>;
+        }
+        pub struct HashScheme(());
+        impl HashScheme {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <HashScheme as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for HashScheme {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 72, 97, 115, 104, 83, 99, 104, 101, 109,
+                101,
+            ];
+            type Params = ();
+            type Reply = super::HashScheme;
+        }
+        pub struct IsApprovedForAll(());
+        impl IsApprovedForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, operator: ActorId) -> Vec<u8> {
+                <IsApprovedForAll as ActionIo>::encode_call(&(owner, operator))
+            }
+        }
+        impl ActionIo for IsApprovedForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 73, 115, 65, 112, 112, 114, 111, 118, 101,
+                100, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = bool;
+        }
+        pub struct NameOfNode(());
+        impl NameOfNode {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <NameOfNode as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for NameOfNode {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 78, 97, 109, 101, 79, 102, 78, 111, 100,
+                101,
+            ];
+            type Params = U256;
+            type Reply = Option<String>;
+        }
+        pub struct NodesOf(());
+        impl NodesOf {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, cursor: Option<U256>, limit: u32) -> Vec<u8> {
+                <NodesOf as ActionIo>::encode_call(&(owner, cursor, limit))
+            }
+        }
+        impl ActionIo for NodesOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 78, 111, 100, 101, 115, 79, 102,
+            ];
+            type Params = (ActorId, Option<U256>, u32);
+            type Reply = (Vec<U256>, Option<U256>);
+        }
+        pub struct OwnerOf(());
+        impl OwnerOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <OwnerOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for OwnerOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 79, 119, 110, 101, 114, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+        pub struct OwnersPage(());
+        impl OwnersPage {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<U256>, limit: u32) -> Vec<u8> {
+                <OwnersPage as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for OwnersPage {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 79, 119, 110, 101, 114, 115, 80, 97, 103,
+                101,
+            ];
+            type Params = (Option<U256>, u32);
+            type Reply = (Vec<(U256, ActorId)>, Option<U256>);
+        }
+        pub struct ParentOf(());
+        impl ParentOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <ParentOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for ParentOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 80, 97, 114, 101, 110, 116, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<(U256, U256)>;
+        }
+        pub struct QueryState(());
+        impl QueryState {
+            #[allow(dead_code)]
+            pub fn encode_call(cursor: Option<U256>, limit: u32) -> Vec<u8> {
+                <QueryState as ActionIo>::encode_call(&(cursor, limit))
+            }
+        }
+        impl ActionIo for QueryState {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 81, 117, 101, 114, 121, 83, 116, 97, 116,
+                101,
+            ];
+            type Params = (Option<U256>, u32);
+            type Reply = super::IoRegistryState;
+        }
+        pub struct ResolverOf(());
+        impl ResolverOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <ResolverOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for ResolverOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101, 115, 111, 108, 118, 101, 114, 79,
+                102,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+        pub struct TtlOf(());
+        impl TtlOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <TtlOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for TtlOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 20, 84, 116, 108, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<u64>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ServiceEvents {
+            NewOwner {
+                node: U256,
+                owner: ActorId,
+            },
+            NewResolver {
+                node: U256,
+                resolver: ActorId,
+            },
+            NewTTL {
+                node: U256,
+                ttl: u64,
+            },
+            NewSubnodeOwner {
+                parent: U256,
+                label: U256,
+                subnode: U256,
+                owner: ActorId,
+            },
+            ControllerAdded(ActorId),
+            ControllerRemoved(ActorId),
+            NewExpiry {
+                node: U256,
+                expiry: u64,
+            },
+            PreimageAdded {
+                labelhash: U256,
+                label: String,
+            },
+            RootNameSet {
+                node: U256,
+                name: String,
+            },
+            ApprovalForAll {
+                owner: ActorId,
+                operator: ActorId,
+                approved: bool,
+            },
+        }
+        impl EventIo for ServiceEvents {
+            const ROUTE: &'static [u8] = &[28, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[32, 78, 101, 119, 79, 119, 110, 101, 114],
+                &[44, 78, 101, 119, 82, 101, 115, 111, 108, 118, 101, 114],
+                &[24, 78, 101, 119, 84, 84, 76],
+                &[
+                    60, 78, 101, 119, 83, 117, 98, 110, 111, 100, 101, 79, 119, 110, 101, 114,
+                ],
+                &[
+                    60, 67, 111, 110, 116, 114, 111, 108, 108, 101, 114, 65, 100, 100, 101, 100,
+                ],
+                &[
+                    68, 67, 111, 110, 116, 114, 111, 108, 108, 101, 114, 82, 101, 109, 111, 118,
+                    101, 100,
+                ],
+                &[36, 78, 101, 119, 69, 120, 112, 105, 114, 121],
+                &[
+                    52, 80, 114, 101, 105, 109, 97, 103, 101, 65, 100, 100, 101, 100,
+                ],
+                &[44, 82, 111, 111, 116, 78, 97, 109, 101, 83, 101, 116],
+                &[
+                    56, 65, 112, 112, 114, 111, 118, 97, 108, 70, 111, 114, 65, 108, 108,
+                ],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<ServiceEvents> {
+            RemotingListener::<_, ServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitRegistry {
+    pub admin: ActorId,
+    pub root_node: U256,
+    pub root_owner: ActorId,
+    /// Subnode derivation used for the lifetime of the registry.
+    pub hash_scheme: HashScheme,
+}
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum RegistryError {
+    NotOwnerOrController,
+    NotParentOwnerOrController,
+    NotAdmin,
+    /// Plaintext labels must be non-empty, dot-free and at most 256 bytes
+    InvalidLabel,
+    /// The parent has no subnode with this label
+    UnknownLabel,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct IoRegistryState {
+    pub admin: ActorId,
+    pub controllers: Vec<ActorId>,
+    pub hash_scheme: HashScheme,
+    /// Node owners, in node order, as stored (see `owners_page`)
+    pub owners: Vec<(U256, ActorId)>,
+    /// Cursor of the next page of `owners`, if there are more
+    pub next_cursor: Option<U256>,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, init: InitRegistry) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn add_controller(
+            &mut self,
+            controller: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn add_preimage(
+            &mut self,
+            parent: U256,
+            label: String,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn remove_controller(
+            &mut self,
+            controller: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_approval_for_all(
+            &mut self,
+            operator: ActorId,
+            approved: bool,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_owner(
+            &mut self,
+            node: U256,
+            new_owner: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_resolver(
+            &mut self,
+            node: U256,
+            resolver: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_root_name(
+            &mut self,
+            name: String,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_subnode_expiry(
+            &mut self,
+            parent: U256,
+            label: U256,
+            expiry: u64,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_subnode_owner(
+            &mut self,
+            parent: U256,
+            label: U256,
+            new_owner: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_subnode_owner_with_expiry(
+            &mut self,
+            parent: U256,
+            label: U256,
+            new_owner: ActorId,
+            expiry: u64,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_subnode_owner_with_label(
+            &mut self,
+            parent: U256,
+            label: String,
+            new_owner: ActorId,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn set_ttl(
+            &mut self,
+            node: U256,
+            ttl: u64,
+        ) -> impl Call<Output = Result<RegistryEvent, RegistryError>, Args = Self::Args>;
+        fn children_of(
+            &self,
+            parent: U256,
+            cursor: Option<U256>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<(U256, U256)>, Option<U256>), Args = Self::Args>;
+        fn expiries_page(
+            &self,
+            cursor: Option<U256>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<(U256, u64)>, Option<U256>), Args = Self::Args>;
+        fn expiry_of(&self, node: U256) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+        fn hash_scheme(&self) -> impl Query<Output = HashScheme, Args = Self::Args>;
+        fn is_approved_for_all(
+            &self,
+            owner: ActorId,
+            operator: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn name_of_node(
+            &self,
+            node: U256,
+        ) -> impl Query<Output = Option<String>, Args = Self::Args>;
+        fn nodes_of(
+            &self,
+            owner: ActorId,
+            cursor: Option<U256>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<U256>, Option<U256>), Args = Self::Args>;
+        fn owner_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn owners_page(
+            &self,
+            cursor: Option<U256>,
+            limit: u32,
+        ) -> impl Query<Output = (Vec<(U256, ActorId)>, Option<U256>), Args = Self::Args>;
+        fn parent_of(
+            &self,
+            node: U256,
+        ) -> impl Query<Output = Option<(U256, U256)>, Args = Self::Args>;
+        fn query_state(
+            &self,
+            cursor: Option<U256>,
+            limit: u32,
+        ) -> impl Query<Output = IoRegistryState, Args = Self::Args>;
+        fn resolver_of(
+            &self,
+            node: U256,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn ttl_of(&self, node: U256) -> impl Query<Output = Option<u64>, Args = Self::Args>;
+    }
+}
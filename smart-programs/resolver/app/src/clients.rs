@@ -0,0 +1,9 @@
+/// Client of the registry program, generated from `idls/registry.idl` by build.rs
+pub mod registry_client {
+    include!(concat!(env!("OUT_DIR"), "/registry_client.rs"));
+}
+
+/// Client of the name wrapper program, generated from `idls/name-wrapper.idl` by build.rs
+pub mod name_wrapper_client {
+    include!(concat!(env!("OUT_DIR"), "/name_wrapper_client.rs"));
+}
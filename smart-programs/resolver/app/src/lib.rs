@@ -1,6 +1,7 @@
 
 #![no_std]
 use sails_rs::prelude::*;
+pub mod clients;
 pub mod services;
 
 use services::service::Service;
@@ -11,11 +12,18 @@ pub struct Program;
 impl Program {
     /// Construct a new Resolver program. Must supply the registry contract address.
     pub fn new(registry: ActorId) -> Self {
-        Service::seed(registry);
+        Service::seed(registry, None);
         Self
     }
 
-    #[route("Service")]
+    /// Construct a new Resolver program that also recognizes the owners of names held by
+    /// the name wrapper.
+    pub fn new_with_name_wrapper(registry: ActorId, name_wrapper: ActorId) -> Self {
+        Service::seed(registry, Some(name_wrapper));
+        Self
+    }
+
+    #[export(route = "Service")]
     pub fn service(&self) -> Service {
         Service::new()
     }
@@ -1,25 +1,34 @@
-              
-#![no_std]
 #![allow(static_mut_refs)]
 
 use sails_rs::{
     prelude::*,
-    gstd::{msg, exec},
-    collections::HashMap,
+    gstd::msg,
+    collections::{HashMap, HashSet},
 };
 use sails_rs::calls::ActionIo; 
 
-pub type Node = [u8; 32];
+use crate::clients::name_wrapper_client::service::io as name_wrapper_io;
+use crate::clients::registry_client::service::io as registry_io;
+
+pub use vns_common::{coins, Node};
 pub type Addr = ActorId;
 
 /// State struct for the Resolver contract
 #[derive(Debug, Clone, Default)]
 pub struct ResolverState {
     pub addresses: HashMap<Node, Addr>,
+    /// Addresses on other chains, by SLIP-44 coin type, in their binary form
+    pub coin_addresses: HashMap<(Node, u32), Vec<u8>>,
     pub texts: HashMap<(Node, String), String>,
     pub contenthashes: HashMap<Node, Vec<u8>>,
-    pub operators: HashMap<Node, Vec<Addr>>,
+    /// Name records, used on reverse nodes to point an address back to its name
+    pub names: HashMap<Node, String>,
+    /// (owner, operator) pairs where the operator may edit the records of all of the
+    /// owner's names. Keyed by owner, so approvals do not carry over to a name's next owner.
+    pub operators: HashSet<(Addr, Addr)>,
     pub registry: Addr,
+    /// Name wrapper whose names are owned by the wrapper's owner rather than the wrapper
+    pub name_wrapper: Option<Addr>,
 }
 
 /// All contract events
@@ -27,10 +36,33 @@ pub struct ResolverState {
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub enum ResolverEvent {
-    AddrChanged(Node, Addr),
+    /// Address for a SLIP-44 coin type, in its binary form; `coins::VARA` for the native
+    /// `addr` record. Empty when the record was removed.
+    AddrChanged(Node, u32, Vec<u8>),
     TextChanged(Node, String, String),
     ContenthashChanged(Node, Vec<u8>),
-    OperatorSet(Node, Addr, bool),
+    /// Owner, operator and whether the operator is approved
+    ApprovalForAll(Addr, Addr, bool),
+    NameChanged(Node, String),
+}
+
+/// All contract errors
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ResolverError {
+    KeyTooLong,
+    ValueTooLong,
+    ContenthashTooLong,
+    TooManyTexts,
+    TooManyContenthashes,
+    NodeHasNoOwner,
+    NotAuthorized,
+    RegistryCallFailed,
+    NameTooLong,
+    InvalidAddress,
+    TooManyAddresses,
+    NameWrapperCallFailed,
 }
 
 /// Arguments for address/text/content queries
@@ -80,33 +112,47 @@ fn state_ref() -> &'static ResolverState {
     unsafe { s.unwrap_unchecked() }
 }
 
-async fn check_can_modify(node: &Node) {
-    let caller = msg::source();
+/// Ask the registry for the owner of `node`. Fails if the registry cannot be reached or
+/// replies with an error, so that no record is modified on an unverified ownership claim.
+async fn registry_owner_of(node: &Node) -> Result<Option<Addr>, ResolverError> {
     let state = state_ref();
-
-    let request = ("owner".to_string(), node.clone()).encode();
+    let request = registry_io::OwnerOf::encode_call(*node);
     let bytes_reply = msg::send_bytes_for_reply(state.registry, request, 0, 0)
-        .expect("send failed")
+        .map_err(|_| ResolverError::RegistryCallFailed)?
+        .await
+        .map_err(|_| ResolverError::RegistryCallFailed)?;
+    registry_io::OwnerOf::decode_reply(bytes_reply).map_err(|_| ResolverError::RegistryCallFailed)
+}
+
+/// Owner of `node`: its owner in the registry or, for names held by the name wrapper, the
+/// owner the wrapper holds it for.
+async fn node_owner_of(node: &Node) -> Result<Option<Addr>, ResolverError> {
+    let owner = registry_owner_of(node).await?;
+    let Some(name_wrapper) = state_ref().name_wrapper.filter(|wrapper| owner == Some(*wrapper)) else {
+        return Ok(owner);
+    };
+    let request = name_wrapper_io::OwnerOf::encode_call(*node);
+    let bytes_reply = msg::send_bytes_for_reply(name_wrapper, request, 0, 0)
+        .map_err(|_| ResolverError::NameWrapperCallFailed)?
         .await
-        .expect("reply failed");
-    let owner: Addr = decode_owner_reply(bytes_reply);
+        .map_err(|_| ResolverError::NameWrapperCallFailed)?;
+    name_wrapper_io::OwnerOf::decode_reply(bytes_reply).map_err(|_| ResolverError::NameWrapperCallFailed)
+}
+
+async fn check_can_modify(node: &Node) -> Result<(), ResolverError> {
+    let caller = msg::source();
+
+    // Nodes without an owner in the registry cannot be modified, not even by operators.
+    let owner = node_owner_of(node).await?.ok_or(ResolverError::NodeHasNoOwner)?;
     if owner == caller {
-        return;
+        return Ok(());
     }
 
-    // Check if caller is operator
-    let operators = state.operators.get(node);
-    if let Some(ops) = operators {
-        if ops.contains(&caller) {
-            return;
-        }
+    // Check if caller is an operator of the owner
+    if state_ref().operators.contains(&(owner, caller)) {
+        return Ok(());
     }
-    panic!("Not authorized");
-}
-
-// Decodes the reply from registry's "owner" query
-fn decode_owner_reply(bytes: Vec<u8>) -> Addr {
-    ActorId::decode(&mut &bytes[..]).expect("Failed to decode registry reply")
+    Err(ResolverError::NotAuthorized)
 }
 
 #[derive(Default)]
@@ -114,10 +160,11 @@ pub struct Service;
 
 impl Service {
     /// Seed/init: must provide registry address
-    pub fn seed(registry: Addr) {
+    pub fn seed(registry: Addr, name_wrapper: Option<Addr>) {
         unsafe {
             RESOLVER_STATE = Some(ResolverState {
                 registry,
+                name_wrapper,
                 ..Default::default()
             })
         }
@@ -131,79 +178,116 @@ impl Service {
     }
 
     /// Set the resolved address for a node (must be owner or operator)
-    pub async fn set_addr(&mut self, node: Node, addr: Addr) -> ResolverEvent {
-        check_can_modify(&node).await;
+    pub async fn set_addr(&mut self, node: Node, addr: Addr) -> Result<ResolverEvent, ResolverError> {
+        check_can_modify(&node).await?;
         let state = state_mut();
         state.addresses.insert(node, addr);
-        self.emit_event(ResolverEvent::AddrChanged(node, addr)).expect("event emission failed"); 
-        ResolverEvent::AddrChanged(node, addr)
+        let address = addr.as_ref().to_vec();
+        self.emit_event(ResolverEvent::AddrChanged(node, coins::VARA, address.clone())).expect("event emission failed"); 
+        Ok(ResolverEvent::AddrChanged(node, coins::VARA, address))
+    }
+
+    /// Set the address of a node on another chain, identified by its SLIP-44 coin type
+    /// (see `vns_common::coins`). `address` is the binary form: the `scriptPubkey` for
+    /// Bitcoin, 20 bytes for Ethereum, the 32-byte account id for SS58 chains. An empty
+    /// `address` removes the record. `coins::VARA` addresses are the native `addr` records.
+    pub async fn set_addr_for_coin(&mut self, node: Node, coin_type: u32, address: Vec<u8>) -> Result<ResolverEvent, ResolverError> {
+        if !address.is_empty() && !coins::is_valid_address(coin_type, &address) {
+            return Err(ResolverError::InvalidAddress);
+        }
+
+        check_can_modify(&node).await?;
+        let state = state_mut();
+        let entry = (node, coin_type);
+
+        if coin_type == coins::VARA {
+            match <[u8; 32]>::try_from(address.as_slice()) {
+                Ok(bytes) => state.addresses.insert(node, bytes.into()),
+                Err(_) => state.addresses.remove(&node),
+            };
+        } else if address.is_empty() {
+            state.coin_addresses.remove(&entry);
+        } else {
+            if !state.coin_addresses.contains_key(&entry) && state.coin_addresses.len() >= 1000 {
+                return Err(ResolverError::TooManyAddresses);
+            }
+            state.coin_addresses.insert(entry, address.clone());
+        }
+
+        self.emit_event(ResolverEvent::AddrChanged(node, coin_type, address.clone())).expect("event emission failed"); 
+        Ok(ResolverEvent::AddrChanged(node, coin_type, address))
     }
 
     /// Set a text value for a node
-    pub async fn set_text(&mut self, node: Node, key: String, value: String) -> ResolverEvent {
+    pub async fn set_text(&mut self, node: Node, key: String, value: String) -> Result<ResolverEvent, ResolverError> {
        
-        assert!(key.len() <= 256, "key length exceeds 256 characters"); 
-        assert!(value.len() <= 1024, "value length exceeds 1024 characters"); 
+        if key.len() > 256 {
+            return Err(ResolverError::KeyTooLong);
+        }
+        if value.len() > 1024 {
+            return Err(ResolverError::ValueTooLong);
+        }
 
-        check_can_modify(&node).await;
+        check_can_modify(&node).await?;
         let state = state_mut();
-        state.texts.insert((node, key.clone()), value.clone());
+        let entry = (node, key.clone());
 
        
-        assert!(state.texts.len() <= 1000, "texts map size limit exceeded"); 
+        if !state.texts.contains_key(&entry) && state.texts.len() >= 1000 {
+            return Err(ResolverError::TooManyTexts);
+        }
+        state.texts.insert(entry, value.clone());
 
         self.emit_event(ResolverEvent::TextChanged(node, key.clone(), value.clone())).expect("event emission failed"); 
+        Ok(ResolverEvent::TextChanged(node, key, value))
     }
 
     /// Set contenthash for a node
-    pub async fn set_contenthash(&mut self, node: Node, data: Vec<u8>) -> ResolverEvent {
+    pub async fn set_contenthash(&mut self, node: Node, data: Vec<u8>) -> Result<ResolverEvent, ResolverError> {
        
-        assert!(data.len() <= 2048, "contenthash data size exceeds 2048 bytes"); 
+        if data.len() > 2048 {
+            return Err(ResolverError::ContenthashTooLong);
+        }
 
-        check_can_modify(&node).await;
+        check_can_modify(&node).await?;
         let state = state_mut();
-        state.contenthashes.insert(node, data.clone());
 
       
-        assert!(state.contenthashes.len() <= 1000, "contenthashes map size limit exceeded"); 
+        if !state.contenthashes.contains_key(&node) && state.contenthashes.len() >= 1000 {
+            return Err(ResolverError::TooManyContenthashes);
+        }
+        state.contenthashes.insert(node, data.clone());
 
         self.emit_event(ResolverEvent::ContenthashChanged(node, data.clone())).expect("event emission failed"); 
-        ResolverEvent::ContenthashChanged(node, data)
+        Ok(ResolverEvent::ContenthashChanged(node, data))
     }
 
-    /// Set/unset an operator for a node (can only be called by owner)
-    pub async fn set_operator(&mut self, node: Node, operator: Addr, enabled: bool) -> ResolverEvent {
-        // Only owner may call (not other operators!)
-        let state = state_ref();
-
-        // Query registry for node owner
-        let request = ("owner".to_string(), node.clone()).encode();
-        let bytes_reply = msg::send_bytes_for_reply(state.registry, request, 0, 0)
-            .expect("send failed")
-            .await
-            .expect("reply failed");
-        let owner: Addr = decode_owner_reply(bytes_reply);
-
-        if msg::source() != owner {
-            panic!("Only node owner can set operators");
+    /// Set the name record for a node, typically a reverse node under `addr.reverse`
+    pub async fn set_name(&mut self, node: Node, name: String) -> Result<ResolverEvent, ResolverError> {
+       
+        if name.len() > 1024 {
+            return Err(ResolverError::NameTooLong);
         }
+
+        check_can_modify(&node).await?;
         let state = state_mut();
-        let operators = state.operators.entry(node).or_default();
+        state.names.insert(node, name.clone());
 
-        const MAX_OPERATORS: usize = 100;
+        self.emit_event(ResolverEvent::NameChanged(node, name.clone())).expect("event emission failed"); 
+        Ok(ResolverEvent::NameChanged(node, name))
+    }
 
-        if enabled {
-            if !operators.contains(&operator) {
-                assert!(operators.len() < MAX_OPERATORS, "operators list size limit exceeded"); 
-                operators.push(operator);
-            }
+    /// Allow or disallow `operator` to edit the records of all of the caller's names
+    pub fn set_approval_for_all(&mut self, operator: Addr, approved: bool) -> Result<ResolverEvent, ResolverError> {
+        let owner = msg::source();
+        let state = state_mut();
+        if approved {
+            state.operators.insert((owner, operator));
         } else {
-            if let Some(pos) = operators.iter().position(|x| x == &operator) {
-                operators.remove(pos);
-            }
+            state.operators.remove(&(owner, operator));
         }
-        self.emit_event(ResolverEvent::OperatorSet(node, operator, enabled)).expect("event emission failed"); 
-        ResolverEvent::OperatorSet(node, operator, enabled)
+        self.emit_event(ResolverEvent::ApprovalForAll(owner, operator, approved)).expect("event emission failed");
+        Ok(ResolverEvent::ApprovalForAll(owner, operator, approved))
     }
 
     /// QUERY: Get address for a node
@@ -211,6 +295,14 @@ impl Service {
         state_ref().addresses.get(&node).cloned()
     }
 
+    /// QUERY: Get the address of a node for a SLIP-44 coin type
+    pub fn addr_of_coin(&self, node: Node, coin_type: u32) -> Option<Vec<u8>> {
+        if coin_type == coins::VARA {
+            return state_ref().addresses.get(&node).map(|addr| addr.as_ref().to_vec());
+        }
+        state_ref().coin_addresses.get(&(node, coin_type)).cloned()
+    }
+
     /// QUERY: Get a text record for a node and key
     pub fn text_of(&self, node: Node, key: String) -> Option<String> {
        
@@ -225,12 +317,13 @@ impl Service {
         state_ref().contenthashes.get(&node).cloned()
     }
 
-    /// QUERY: Is addr an operator for node
-    pub fn is_operator(&self, node: Node, operator: Addr) -> bool {
-        state_ref()
-            .operators
-            .get(&node)
-            .map(|ops| ops.contains(&operator))
-            .unwrap_or(false)
+    /// QUERY: Get the name record for a node
+    pub fn name_of(&self, node: Node) -> Option<String> {
+        state_ref().names.get(&node).cloned()
+    }
+
+    /// QUERY: Is addr an operator of owner
+    pub fn is_approved_for_all(&self, owner: Addr, operator: Addr) -> bool {
+        state_ref().operators.contains(&(owner, operator))
     }
 }
\ No newline at end of file
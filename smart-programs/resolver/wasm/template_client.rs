@@ -0,0 +1,495 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    /// Construct a new Resolver program. Must supply the registry contract address.
+    fn new(&self, registry: ActorId) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), registry)
+    }
+    /// Construct a new Resolver program that also recognizes the owners of names held by
+    /// the name wrapper.
+    fn new_with_name_wrapper(
+        &self,
+        registry: ActorId,
+        name_wrapper: ActorId,
+    ) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::NewWithNameWrapper>::new(
+            self.remoting.clone(),
+            (registry, name_wrapper),
+        )
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(registry: ActorId) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&registry)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = ActorId;
+            type Reply = ();
+        }
+        pub struct NewWithNameWrapper(());
+        impl NewWithNameWrapper {
+            #[allow(dead_code)]
+            pub fn encode_call(registry: ActorId, name_wrapper: ActorId) -> Vec<u8> {
+                <NewWithNameWrapper as ActionIo>::encode_call(&(registry, name_wrapper))
+            }
+        }
+        impl ActionIo for NewWithNameWrapper {
+            const ROUTE: &'static [u8] = &[
+                72, 78, 101, 119, 87, 105, 116, 104, 78, 97, 109, 101, 87, 114, 97, 112, 112, 101,
+                114,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Set the resolved address for a node (must be owner or operator)
+    fn set_addr(
+        &mut self,
+        node: U256,
+        addr: ActorId,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetAddr>::new(self.remoting.clone(), (node, addr))
+    }
+    /// Set the address of a node on another chain, identified by its SLIP-44 coin type
+    /// (see `vns_common::coins`). `address` is the binary form: the `scriptPubkey` for
+    /// Bitcoin, 20 bytes for Ethereum, the 32-byte account id for SS58 chains. An empty
+    /// `address` removes the record. `coins::VARA` addresses are the native `addr` records.
+    fn set_addr_for_coin(
+        &mut self,
+        node: U256,
+        coin_type: u32,
+        address: Vec<u8>,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetAddrForCoin>::new(
+            self.remoting.clone(),
+            (node, coin_type, address),
+        )
+    }
+    /// Allow or disallow `operator` to edit the records of all of the caller's names
+    fn set_approval_for_all(
+        &mut self,
+        operator: ActorId,
+        approved: bool,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetApprovalForAll>::new(
+            self.remoting.clone(),
+            (operator, approved),
+        )
+    }
+    /// Set contenthash for a node
+    fn set_contenthash(
+        &mut self,
+        node: U256,
+        data: Vec<u8>,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetContenthash>::new(self.remoting.clone(), (node, data))
+    }
+    /// Set the name record for a node, typically a reverse node under `addr.reverse`
+    fn set_name(
+        &mut self,
+        node: U256,
+        name: String,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetName>::new(self.remoting.clone(), (node, name))
+    }
+    /// Set a text value for a node
+    fn set_text(
+        &mut self,
+        node: U256,
+        key: String,
+        value: String,
+    ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::SetText>::new(self.remoting.clone(), (node, key, value))
+    }
+    /// QUERY: Get address for a node
+    fn addr_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, service::io::AddrOf>::new(self.remoting.clone(), node)
+    }
+    /// QUERY: Get the address of a node for a SLIP-44 coin type
+    fn addr_of_coin(
+        &self,
+        node: U256,
+        coin_type: u32,
+    ) -> impl Query<Output = Option<Vec<u8>>, Args = R::Args> {
+        RemotingAction::<_, service::io::AddrOfCoin>::new(self.remoting.clone(), (node, coin_type))
+    }
+    /// QUERY: Get contenthash for a node
+    fn contenthash_of(&self, node: U256) -> impl Query<Output = Option<Vec<u8>>, Args = R::Args> {
+        RemotingAction::<_, service::io::ContenthashOf>::new(self.remoting.clone(), node)
+    }
+    /// QUERY: Is addr an operator of owner
+    fn is_approved_for_all(
+        &self,
+        owner: ActorId,
+        operator: ActorId,
+    ) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, service::io::IsApprovedForAll>::new(
+            self.remoting.clone(),
+            (owner, operator),
+        )
+    }
+    /// QUERY: Get the name record for a node
+    fn name_of(&self, node: U256) -> impl Query<Output = Option<String>, Args = R::Args> {
+        RemotingAction::<_, service::io::NameOf>::new(self.remoting.clone(), node)
+    }
+    /// QUERY: Get a text record for a node and key
+    fn text_of(
+        &self,
+        node: U256,
+        key: String,
+    ) -> impl Query<Output = Option<String>, Args = R::Args> {
+        RemotingAction::<_, service::io::TextOf>::new(self.remoting.clone(), (node, key))
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct SetAddr(());
+        impl SetAddr {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, addr: ActorId) -> Vec<u8> {
+                <SetAddr as ActionIo>::encode_call(&(node, addr))
+            }
+        }
+        impl ActionIo for SetAddr {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 83, 101, 116, 65, 100, 100, 114,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct SetAddrForCoin(());
+        impl SetAddrForCoin {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, coin_type: u32, address: Vec<u8>) -> Vec<u8> {
+                <SetAddrForCoin as ActionIo>::encode_call(&(node, coin_type, address))
+            }
+        }
+        impl ActionIo for SetAddrForCoin {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101, 116, 65, 100, 100, 114, 70, 111,
+                114, 67, 111, 105, 110,
+            ];
+            type Params = (U256, u32, Vec<u8>);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct SetApprovalForAll(());
+        impl SetApprovalForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(operator: ActorId, approved: bool) -> Vec<u8> {
+                <SetApprovalForAll as ActionIo>::encode_call(&(operator, approved))
+            }
+        }
+        impl ActionIo for SetApprovalForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 68, 83, 101, 116, 65, 112, 112, 114, 111, 118,
+                97, 108, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, bool);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct SetContenthash(());
+        impl SetContenthash {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, data: Vec<u8>) -> Vec<u8> {
+                <SetContenthash as ActionIo>::encode_call(&(node, data))
+            }
+        }
+        impl ActionIo for SetContenthash {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101, 116, 67, 111, 110, 116, 101, 110,
+                116, 104, 97, 115, 104,
+            ];
+            type Params = (U256, Vec<u8>);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct SetName(());
+        impl SetName {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, name: String) -> Vec<u8> {
+                <SetName as ActionIo>::encode_call(&(node, name))
+            }
+        }
+        impl ActionIo for SetName {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 83, 101, 116, 78, 97, 109, 101,
+            ];
+            type Params = (U256, String);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct SetText(());
+        impl SetText {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, key: String, value: String) -> Vec<u8> {
+                <SetText as ActionIo>::encode_call(&(node, key, value))
+            }
+        }
+        impl ActionIo for SetText {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 83, 101, 116, 84, 101, 120, 116,
+            ];
+            type Params = (U256, String, String);
+            type Reply = Result<super::ResolverEvent, super::ResolverError>;
+        }
+        pub struct AddrOf(());
+        impl AddrOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <AddrOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for AddrOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 65, 100, 100, 114, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<ActorId>;
+        }
+        pub struct AddrOfCoin(());
+        impl AddrOfCoin {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, coin_type: u32) -> Vec<u8> {
+                <AddrOfCoin as ActionIo>::encode_call(&(node, coin_type))
+            }
+        }
+        impl ActionIo for AddrOfCoin {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 40, 65, 100, 100, 114, 79, 102, 67, 111, 105,
+                110,
+            ];
+            type Params = (U256, u32);
+            type Reply = Option<Vec<u8>>;
+        }
+        pub struct ContenthashOf(());
+        impl ContenthashOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <ContenthashOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for ContenthashOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 52, 67, 111, 110, 116, 101, 110, 116, 104, 97,
+                115, 104, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<Vec<u8>>;
+        }
+        pub struct IsApprovedForAll(());
+        impl IsApprovedForAll {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, operator: ActorId) -> Vec<u8> {
+                <IsApprovedForAll as ActionIo>::encode_call(&(owner, operator))
+            }
+        }
+        impl ActionIo for IsApprovedForAll {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 64, 73, 115, 65, 112, 112, 114, 111, 118, 101,
+                100, 70, 111, 114, 65, 108, 108,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = bool;
+        }
+        pub struct NameOf(());
+        impl NameOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256) -> Vec<u8> {
+                <NameOf as ActionIo>::encode_call(&node)
+            }
+        }
+        impl ActionIo for NameOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 78, 97, 109, 101, 79, 102,
+            ];
+            type Params = U256;
+            type Reply = Option<String>;
+        }
+        pub struct TextOf(());
+        impl TextOf {
+            #[allow(dead_code)]
+            pub fn encode_call(node: U256, key: String) -> Vec<u8> {
+                <TextOf as ActionIo>::encode_call(&(node, key))
+            }
+        }
+        impl ActionIo for TextOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 84, 101, 120, 116, 79, 102,
+            ];
+            type Params = (U256, String);
+            type Reply = Option<String>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ServiceEvents {
+            /// Address for a SLIP-44 coin type, in its binary form; `coins::VARA` for the native
+            /// `addr` record. Empty when the record was removed.
+            AddrChanged((U256, u32, Vec<u8>)),
+            TextChanged((U256, String, String)),
+            ContenthashChanged((U256, Vec<u8>)),
+            /// Owner, operator and whether the operator is approved
+            ApprovalForAll((ActorId, ActorId, bool)),
+            NameChanged((U256, String)),
+        }
+        impl EventIo for ServiceEvents {
+            const ROUTE: &'static [u8] = &[28, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[44, 65, 100, 100, 114, 67, 104, 97, 110, 103, 101, 100],
+                &[44, 84, 101, 120, 116, 67, 104, 97, 110, 103, 101, 100],
+                &[
+                    72, 67, 111, 110, 116, 101, 110, 116, 104, 97, 115, 104, 67, 104, 97, 110, 103,
+                    101, 100,
+                ],
+                &[
+                    56, 65, 112, 112, 114, 111, 118, 97, 108, 70, 111, 114, 65, 108, 108,
+                ],
+                &[44, 78, 97, 109, 101, 67, 104, 97, 110, 103, 101, 100],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<ServiceEvents> {
+            RemotingListener::<_, ServiceEvents>::new(remoting)
+        }
+    }
+}
+/// All contract errors
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ResolverError {
+    KeyTooLong,
+    ValueTooLong,
+    ContenthashTooLong,
+    TooManyTexts,
+    TooManyContenthashes,
+    NodeHasNoOwner,
+    NotAuthorized,
+    RegistryCallFailed,
+    NameTooLong,
+    InvalidAddress,
+    TooManyAddresses,
+    NameWrapperCallFailed,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, registry: ActorId) -> impl Activation<Args = Self::Args>;
+        fn new_with_name_wrapper(
+            &self,
+            registry: ActorId,
+            name_wrapper: ActorId,
+        ) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn set_addr(
+            &mut self,
+            node: U256,
+            addr: ActorId,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn set_addr_for_coin(
+            &mut self,
+            node: U256,
+            coin_type: u32,
+            address: Vec<u8>,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn set_approval_for_all(
+            &mut self,
+            operator: ActorId,
+            approved: bool,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn set_contenthash(
+            &mut self,
+            node: U256,
+            data: Vec<u8>,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn set_name(
+            &mut self,
+            node: U256,
+            name: String,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn set_text(
+            &mut self,
+            node: U256,
+            key: String,
+            value: String,
+        ) -> impl Call<Output = Result<ResolverEvent, ResolverError>, Args = Self::Args>;
+        fn addr_of(&self, node: U256) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn addr_of_coin(
+            &self,
+            node: U256,
+            coin_type: u32,
+        ) -> impl Query<Output = Option<Vec<u8>>, Args = Self::Args>;
+        fn contenthash_of(
+            &self,
+            node: U256,
+        ) -> impl Query<Output = Option<Vec<u8>>, Args = Self::Args>;
+        fn is_approved_for_all(
+            &self,
+            owner: ActorId,
+            operator: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn name_of(&self, node: U256) -> impl Query<Output = Option<String>, Args = Self::Args>;
+        fn text_of(
+            &self,
+            node: U256,
+            key: String,
+        ) -> impl Query<Output = Option<String>, Args = Self::Args>;
+    }
+}
@@ -0,0 +1,22 @@
+#![no_std]
+use sails_rs::prelude::*;
+pub mod clients;
+pub mod services;
+
+use services::service::{Service, InitReverseRegistrar};
+
+pub struct Program;
+
+#[program]
+impl Program {
+    /// Construct a new ReverseRegistrar program. It must own `addr.reverse` in the registry.
+    pub fn new(init: InitReverseRegistrar) -> Self {
+        Service::seed(init);
+        Self
+    }
+
+    #[export(route = "Service")]
+    pub fn service(&self) -> Service {
+        Service::new()
+    }
+}
@@ -0,0 +1,236 @@
+#![allow(static_mut_refs)]
+
+use sails_rs::{
+    prelude::*,
+    gstd::{exec, msg},
+};
+use sails_rs::calls::ActionIo;
+
+use crate::clients::registry_client::{service::io as registry_io, RegistryEvent};
+use crate::clients::resolver_client::service::io as resolver_io;
+
+pub use vns_common::{HashScheme, Node};
+use vns_common::normalize::{normalize_any_length, LabelError};
+
+const MAX_NAME_LENGTH: usize = 1024;
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ReverseRegistrarEvent {
+    ReverseClaimed { addr: ActorId, node: Node, owner: ActorId },
+    NameSet { addr: ActorId, node: Node, name: String },
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ReverseRegistrarError {
+    InvalidName,
+    RegistryCallFailed,
+    ResolverCallFailed,
+    /// A label of the name does not normalize; see `vns_common::normalize`
+    InvalidLabel(LabelError),
+}
+
+#[derive(Debug, Encode, Decode, Clone, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitReverseRegistrar {
+    pub registry: ActorId,
+    /// Resolver that `set_name` stores primary names in
+    pub default_resolver: ActorId,
+    /// Must match the registry's `hash_scheme`, as reverse nodes are derived here.
+    pub hash_scheme: HashScheme,
+}
+
+#[derive(Debug, Default)]
+pub struct ReverseRegistrarState {
+    pub registry: ActorId,
+    pub default_resolver: ActorId,
+    pub hash_scheme: HashScheme,
+    /// Namehash of `addr.reverse`
+    pub reverse_node: Node,
+}
+
+static mut REVERSE_REGISTRAR_STATE: Option<ReverseRegistrarState> = None;
+
+impl ReverseRegistrarState {
+    pub fn state_ref() -> &'static ReverseRegistrarState {
+        let s = unsafe { REVERSE_REGISTRAR_STATE.as_ref() };
+        debug_assert!(s.is_some(), "Reverse registrar state not initialized");
+        unsafe { s.unwrap_unchecked() }
+    }
+
+    /// Label hash of `addr`'s reverse label, `<hex(addr)>`
+    fn reverse_labelhash(&self, addr: ActorId) -> U256 {
+        self.hash_scheme.labelhash(&vns_common::reverse_label(&addr.into_bytes()))
+    }
+
+    /// Node of `<hex(addr)>.addr.reverse`
+    fn reverse_node_of(&self, addr: ActorId) -> Node {
+        self.hash_scheme.subnode(self.reverse_node, self.reverse_labelhash(addr))
+    }
+}
+
+/// Send an encoded call to `program` and decode its reply.
+/// Returns `None` if the message could not be sent or the program rejected the call.
+async fn call<A: ActionIo>(program: ActorId, request: Vec<u8>) -> Option<A::Reply> {
+    let bytes_reply = msg::send_bytes_for_reply(program, request, 0, 0)
+        .ok()?
+        .await
+        .ok()?;
+    A::decode_reply(bytes_reply).ok()
+}
+
+/// Mint the reverse node of `addr` to `owner`
+async fn claim_reverse(addr: ActorId, owner: ActorId) -> Option<Node> {
+    let s = ReverseRegistrarState::state_ref();
+    let request = registry_io::SetSubnodeOwner::encode_call(s.reverse_node, s.reverse_labelhash(addr), owner);
+    match call::<registry_io::SetSubnodeOwner>(s.registry, request).await? {
+        Ok(RegistryEvent::NewSubnodeOwner { subnode, .. }) => Some(subnode),
+        _ => None,
+    }
+}
+
+/// `name` with each label normalized as the registrar does, so that it namehashes to the
+/// node it was registered under.
+fn normalize_name(name: &str) -> Result<String, ReverseRegistrarError> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH || name.split('.').any(str::is_empty) {
+        return Err(ReverseRegistrarError::InvalidName);
+    }
+    let labels = name
+        .split('.')
+        .map(|label| normalize_any_length(label.as_bytes()).map_err(ReverseRegistrarError::InvalidLabel))
+        .collect::<Result<Vec<_>, _>>()?;
+    String::from_utf8(labels.join(&b'.')).map_err(|_| ReverseRegistrarError::InvalidName)
+}
+
+async fn resolver_of(registry: ActorId, node: Node) -> Result<Option<ActorId>, ReverseRegistrarError> {
+    let request = registry_io::ResolverOf::encode_call(node);
+    call::<registry_io::ResolverOf>(registry, request)
+        .await
+        .ok_or(ReverseRegistrarError::RegistryCallFailed)
+}
+
+#[derive(Default)]
+pub struct Service;
+
+impl Service {
+    pub fn seed(init: InitReverseRegistrar) {
+        let reverse_node = init.hash_scheme.namehash(vns_common::REVERSE_NAME);
+        unsafe {
+            REVERSE_REGISTRAR_STATE = Some(ReverseRegistrarState {
+                registry: init.registry,
+                default_resolver: init.default_resolver,
+                hash_scheme: init.hash_scheme,
+                reverse_node,
+            });
+        }
+    }
+}
+
+#[sails_rs::service(events = ReverseRegistrarEvent)]
+impl Service {
+    pub fn new() -> Self { Self }
+
+    /// Hand the caller's reverse node, `<hex(caller)>.addr.reverse`, to `owner` in the
+    /// registry. The reverse registrar must own `addr.reverse` there.
+    pub async fn claim(&mut self, owner: ActorId) -> Result<ReverseRegistrarEvent, ReverseRegistrarError> {
+        let addr = msg::source();
+        let node = claim_reverse(addr, owner)
+            .await
+            .ok_or(ReverseRegistrarError::RegistryCallFailed)?;
+
+        let event = ReverseRegistrarEvent::ReverseClaimed { addr, node, owner };
+        self.emit_event(event.clone()).expect("Event failed");
+        Ok(event)
+    }
+
+    /// Set the caller's primary name.
+    ///
+    /// Claims the caller's reverse node, points it at the default resolver and stores `name`
+    /// there before handing the node back to the caller. If the resolver rejects the name,
+    /// the node is still handed to the caller and `ResolverCallFailed` is returned.
+    ///
+    /// Each label is normalized first and the normalized name is stored, so `Alice.VARA`
+    /// is kept as `alice.vara`. The name is only reported by `name_of` while it resolves
+    /// forward to the caller.
+    pub async fn set_name(&mut self, name: String) -> Result<ReverseRegistrarEvent, ReverseRegistrarError> {
+        let name = normalize_name(&name)?;
+        let addr = msg::source();
+        let s = ReverseRegistrarState::state_ref();
+        let (registry, resolver) = (s.registry, s.default_resolver);
+
+        let node = claim_reverse(addr, exec::program_id())
+            .await
+            .ok_or(ReverseRegistrarError::RegistryCallFailed)?;
+
+        let request = registry_io::SetResolver::encode_call(node, resolver);
+        let resolver_set = matches!(call::<registry_io::SetResolver>(registry, request).await, Some(Ok(_)));
+
+        let name_set = resolver_set && {
+            let request = resolver_io::SetName::encode_call(node, name.clone());
+            matches!(call::<resolver_io::SetName>(resolver, request).await, Some(Ok(_)))
+        };
+
+        let request = registry_io::SetOwner::encode_call(node, addr);
+        let returned = matches!(call::<registry_io::SetOwner>(registry, request).await, Some(Ok(_)));
+
+        if !resolver_set || !returned {
+            return Err(ReverseRegistrarError::RegistryCallFailed);
+        }
+        if !name_set {
+            return Err(ReverseRegistrarError::ResolverCallFailed);
+        }
+
+        let event = ReverseRegistrarEvent::NameSet { addr, node, name };
+        self.emit_event(event.clone()).expect("Event failed");
+        Ok(event)
+    }
+
+    /// Primary name of `addr`: the name stored on its reverse node, provided that name's
+    /// `addr` record points back at `addr`. Returns `None` if there is no reverse record or
+    /// the forward resolution does not match, so a name cannot be claimed by setting it
+    /// as someone else's reverse record.
+    ///
+    /// Sent as a message rather than read as a query: it needs replies from the registry
+    /// and the resolvers.
+    pub async fn name_of(&mut self, addr: ActorId) -> Result<Option<String>, ReverseRegistrarError> {
+        let s = ReverseRegistrarState::state_ref();
+        let (registry, scheme) = (s.registry, s.hash_scheme);
+        let node = s.reverse_node_of(addr);
+
+        let Some(resolver) = resolver_of(registry, node).await? else {
+            return Ok(None);
+        };
+        let request = resolver_io::NameOf::encode_call(node);
+        let Some(name) = call::<resolver_io::NameOf>(resolver, request)
+            .await
+            .ok_or(ReverseRegistrarError::ResolverCallFailed)?
+        else {
+            return Ok(None);
+        };
+
+        let forward_node = scheme.namehash(&name);
+        let Some(forward_resolver) = resolver_of(registry, forward_node).await? else {
+            return Ok(None);
+        };
+        let request = resolver_io::AddrOf::encode_call(forward_node);
+        let forward_addr = call::<resolver_io::AddrOf>(forward_resolver, request)
+            .await
+            .ok_or(ReverseRegistrarError::ResolverCallFailed)?;
+
+        Ok((forward_addr == Some(addr)).then_some(name))
+    }
+
+    /// QUERY: Reverse node of `addr`, `<hex(addr)>.addr.reverse`
+    pub fn node_of(&self, addr: ActorId) -> Node {
+        ReverseRegistrarState::state_ref().reverse_node_of(addr)
+    }
+
+    /// QUERY: Resolver `set_name` stores primary names in
+    pub fn default_resolver(&self) -> ActorId {
+        ReverseRegistrarState::state_ref().default_resolver
+    }
+}
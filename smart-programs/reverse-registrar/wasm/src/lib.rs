@@ -0,0 +1,4 @@
+#![no_std]
+
+#[cfg(target_arch = "wasm32")]
+pub use app::wasm::*;
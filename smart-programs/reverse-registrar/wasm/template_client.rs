@@ -0,0 +1,291 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    /// Construct a new ReverseRegistrar program. It must own `addr.reverse` in the registry.
+    fn new(&self, init: InitReverseRegistrar) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), init)
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(init: super::InitReverseRegistrar) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&init)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = super::InitReverseRegistrar;
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Hand the caller's reverse node, `<hex(caller)>.addr.reverse`, to `owner` in the
+    /// registry. The reverse registrar must own `addr.reverse` there.
+    fn claim(
+        &mut self,
+        owner: ActorId,
+    ) -> impl Call<Output = Result<ReverseRegistrarEvent, ReverseRegistrarError>, Args = R::Args>
+    {
+        RemotingAction::<_, service::io::Claim>::new(self.remoting.clone(), owner)
+    }
+    /// Primary name of `addr`: the name stored on its reverse node, provided that name's
+    /// `addr` record points back at `addr`. Returns `None` if there is no reverse record or
+    /// the forward resolution does not match, so a name cannot be claimed by setting it
+    /// as someone else's reverse record.
+    ///
+    /// Sent as a message rather than read as a query: it needs replies from the registry
+    /// and the resolvers.
+    fn name_of(
+        &mut self,
+        addr: ActorId,
+    ) -> impl Call<Output = Result<Option<String>, ReverseRegistrarError>, Args = R::Args> {
+        RemotingAction::<_, service::io::NameOf>::new(self.remoting.clone(), addr)
+    }
+    /// Set the caller's primary name.
+    ///
+    /// Claims the caller's reverse node, points it at the default resolver and stores `name`
+    /// there before handing the node back to the caller. If the resolver rejects the name,
+    /// the node is still handed to the caller and `ResolverCallFailed` is returned.
+    ///
+    /// Each label is normalized first and the normalized name is stored, so `Alice.VARA`
+    /// is kept as `alice.vara`. The name is only reported by `name_of` while it resolves
+    /// forward to the caller.
+    fn set_name(
+        &mut self,
+        name: String,
+    ) -> impl Call<Output = Result<ReverseRegistrarEvent, ReverseRegistrarError>, Args = R::Args>
+    {
+        RemotingAction::<_, service::io::SetName>::new(self.remoting.clone(), name)
+    }
+    /// QUERY: Resolver `set_name` stores primary names in
+    fn default_resolver(&self) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, service::io::DefaultResolver>::new(self.remoting.clone(), ())
+    }
+    /// QUERY: Reverse node of `addr`, `<hex(addr)>.addr.reverse`
+    fn node_of(&self, addr: ActorId) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, service::io::NodeOf>::new(self.remoting.clone(), addr)
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Claim(());
+        impl Claim {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <Claim as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for Claim {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 20, 67, 108, 97, 105, 109,
+            ];
+            type Params = ActorId;
+            type Reply = Result<super::ReverseRegistrarEvent, super::ReverseRegistrarError>;
+        }
+        pub struct NameOf(());
+        impl NameOf {
+            #[allow(dead_code)]
+            pub fn encode_call(addr: ActorId) -> Vec<u8> {
+                <NameOf as ActionIo>::encode_call(&addr)
+            }
+        }
+        impl ActionIo for NameOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 78, 97, 109, 101, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = Result<Option<String>, super::ReverseRegistrarError>;
+        }
+        pub struct SetName(());
+        impl SetName {
+            #[allow(dead_code)]
+            pub fn encode_call(name: String) -> Vec<u8> {
+                <SetName as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for SetName {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 83, 101, 116, 78, 97, 109, 101,
+            ];
+            type Params = String;
+            type Reply = Result<super::ReverseRegistrarEvent, super::ReverseRegistrarError>;
+        }
+        pub struct DefaultResolver(());
+        impl DefaultResolver {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <DefaultResolver as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for DefaultResolver {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 60, 68, 101, 102, 97, 117, 108, 116, 82, 101,
+                115, 111, 108, 118, 101, 114,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct NodeOf(());
+        impl NodeOf {
+            #[allow(dead_code)]
+            pub fn encode_call(addr: ActorId) -> Vec<u8> {
+                <NodeOf as ActionIo>::encode_call(&addr)
+            }
+        }
+        impl ActionIo for NodeOf {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 24, 78, 111, 100, 101, 79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = U256;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum ServiceEvents {
+            ReverseClaimed {
+                addr: ActorId,
+                node: U256,
+                owner: ActorId,
+            },
+            NameSet {
+                addr: ActorId,
+                node: U256,
+                name: String,
+            },
+        }
+        impl EventIo for ServiceEvents {
+            const ROUTE: &'static [u8] = &[28, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[
+                    56, 82, 101, 118, 101, 114, 115, 101, 67, 108, 97, 105, 109, 101, 100,
+                ],
+                &[28, 78, 97, 109, 101, 83, 101, 116],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<ServiceEvents> {
+            RemotingListener::<_, ServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitReverseRegistrar {
+    pub registry: ActorId,
+    /// Resolver that `set_name` stores primary names in
+    pub default_resolver: ActorId,
+    /// Must match the registry's `hash_scheme`, as reverse nodes are derived here.
+    pub hash_scheme: HashScheme,
+}
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ReverseRegistrarError {
+    InvalidName,
+    RegistryCallFailed,
+    ResolverCallFailed,
+    /// A label of the name does not normalize; see `vns_common::normalize`
+    InvalidLabel(LabelError),
+}
+/// Why a label was rejected.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LabelError {
+    /// The label is not valid UTF-8.
+    InvalidUtf8,
+    /// The label has fewer than `MIN_LABEL_CHARS` characters.
+    TooShort,
+    /// The label contains a dot, or a character normalizing to one.
+    ContainsDot,
+    /// The label contains a code point that cannot appear in a name.
+    DisallowedCodePoint(u32),
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, init: InitReverseRegistrar) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn claim(
+            &mut self,
+            owner: ActorId,
+        ) -> impl Call<Output = Result<ReverseRegistrarEvent, ReverseRegistrarError>, Args = Self::Args>;
+        fn name_of(
+            &mut self,
+            addr: ActorId,
+        ) -> impl Call<Output = Result<Option<String>, ReverseRegistrarError>, Args = Self::Args>;
+        fn set_name(
+            &mut self,
+            name: String,
+        ) -> impl Call<Output = Result<ReverseRegistrarEvent, ReverseRegistrarError>, Args = Self::Args>;
+        fn default_resolver(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn node_of(&self, addr: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
+    }
+}
@@ -0,0 +1,20 @@
+use sails_client_gen::ClientGenerator;
+use std::{env, path::PathBuf};
+
+fn main() {
+    // Path where the file "Cargo.toml" is located (points to smart-programs/tests)
+    let cargo_toml_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    // Clients are generated into 'OUT_DIR' and included from src/lib.rs
+    let outdir_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // Each program's build writes its IDL next to its wasm crate
+    for program in ["registry", "registrar", "resolver", "universal-resolver", "reverse-registrar", "name-wrapper"] {
+        let idl_path = cargo_toml_path.join(format!("../{program}/wasm/template.idl"));
+        println!("cargo:rerun-if-changed={}", idl_path.display());
+
+        ClientGenerator::from_idl_path(&idl_path)
+            .generate_to(outdir_path.join(format!("{}_client.rs", program.replace('-', "_"))))
+            .unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+//! Clients of the VNS programs used by the integration tests, generated by build.rs from
+//! the IDLs the programs' own builds produce.
+//!
+//! Those IDLs declare a service's event enum only in its `events` section, so the client
+//! generates it as `service::events::ServiceEvents`; commands reply with the same enum, and
+//! it is re-exported under the name their signatures use.
+
+pub mod registry_client {
+    include!(concat!(env!("OUT_DIR"), "/registry_client.rs"));
+    pub use service::events::ServiceEvents as RegistryEvent;
+}
+
+// `Register` takes the full set of registration parameters.
+#[allow(clippy::too_many_arguments)]
+pub mod registrar_client {
+    include!(concat!(env!("OUT_DIR"), "/registrar_client.rs"));
+    pub use service::events::ServiceEvents as RegistrarEvent;
+}
+
+pub mod resolver_client {
+    include!(concat!(env!("OUT_DIR"), "/resolver_client.rs"));
+    pub use service::events::ServiceEvents as ResolverEvent;
+}
+
+pub mod universal_resolver_client {
+    include!(concat!(env!("OUT_DIR"), "/universal_resolver_client.rs"));
+}
+
+pub mod reverse_registrar_client {
+    include!(concat!(env!("OUT_DIR"), "/reverse_registrar_client.rs"));
+    pub use service::events::ServiceEvents as ReverseRegistrarEvent;
+}
+
+pub mod name_wrapper_client {
+    include!(concat!(env!("OUT_DIR"), "/name_wrapper_client.rs"));
+    pub use service::events::ServiceEvents as NameWrapperEvent;
+}
@@ -0,0 +1,1205 @@
+use gtest::{BlockRunResult, Log, Program, System};
+use sails_rs::{calls::ActionIo, prelude::*};
+use vns_common::{coins, commitment::make_commitment, fuses, namehash};
+use vns_tests::{
+    name_wrapper_client::{self, service::io as wrapper_io, NameWrapperError},
+    registrar_client::{self, service::io as registrar_io, LabelError, RegistrarError, RegistrarEvent},
+    registry_client::{self, service::io as registry_io, RegistryError},
+    resolver_client::{self, service::io as resolver_io, ResolverError, ResolverEvent},
+    reverse_registrar_client::{self, service::io as reverse_io, ReverseRegistrarError, ReverseRegistrarEvent},
+    universal_resolver_client::{self, service::io as universal_io, UniversalResolverError},
+};
+
+const ADMIN: u64 = 10;
+const ALICE: u64 = 11;
+const BOB: u64 = 12;
+
+const BLOCK_MS: u64 = 3_000;
+const MIN_COMMIT_AGE: u64 = 2 * BLOCK_MS;
+const MAX_COMMIT_AGE: u64 = 20 * BLOCK_MS;
+const GRACE_PERIOD: u64 = 10 * BLOCK_MS;
+const DURATION: u64 = 100 * BLOCK_MS;
+const YEAR_MS: u64 = 365 * 24 * 60 * 60 * 1000;
+/// Yearly prices of labels of 1, 2, 3, 4, and 5 or more characters
+const PRICES_PER_YEAR: [u128; 5] = [
+    100_000_000_000_000_000,
+    50_000_000_000_000_000,
+    20_000_000_000_000_000,
+    5_000_000_000_000_000,
+    1_000_000_000_000_000,
+];
+const FUNDS: u128 = 1_000_000_000_000_000_000;
+/// Most value a call can spend on gas, as gtest sends messages with the maximum gas limit
+const MAX_FEE: u128 = gtest::constants::MAX_USER_GAS_LIMIT as u128 * gtest::constants::VALUE_PER_GAS;
+
+struct Vns<'a> {
+    sys: &'a System,
+    registry: Program<'a>,
+    registrar: Program<'a>,
+    resolver: Program<'a>,
+    universal: Program<'a>,
+    reverse: Program<'a>,
+    wrapper: Program<'a>,
+}
+
+/// Optimized wasm of a program, at the path its build recorded in `wasm/.binpath`: the
+/// target directory depends on the toolchain.
+fn wasm_of(program: &str) -> String {
+    let wasm_dir = format!("../{program}/wasm");
+    let binpath = std::fs::read_to_string(format!("{wasm_dir}/.binpath")).expect("program not built");
+    format!("{wasm_dir}/{}.opt.wasm", binpath.trim())
+}
+
+fn actor(id: u64) -> ActorId {
+    id.into()
+}
+
+fn reply_of(res: &BlockRunResult, mid: MessageId) -> Vec<u8> {
+    res.log()
+        .iter()
+        .find(|log| log.reply_to() == Some(mid))
+        .map(|log| log.payload().to_vec())
+        .expect("no reply to message")
+}
+
+/// Commitment to registering `name` for `DURATION`, computed off-chain
+fn commitment(name: &[u8], owner: ActorId, secret: [u8; 32], salt: [u8; 32], resolver: Option<ActorId>) -> [u8; 32] {
+    let resolver = resolver.map(ActorId::into_bytes);
+    make_commitment(name, &owner.into_bytes(), &secret, &salt, DURATION, resolver.as_ref())
+}
+
+impl<'a> Vns<'a> {
+    /// Deploy the programs wired together: the registrar owns `vara` in the registry, the
+    /// reverse registrar owns `addr.reverse`, and the resolver checks ownership
+    /// against the same registry and name wrapper.
+    fn deploy(sys: &'a System) -> Self {
+        for user in [ADMIN, ALICE, BOB] {
+            sys.mint_to(user, FUNDS);
+        }
+        let registry = Program::from_file(sys, wasm_of("registry"));
+        let registrar = Program::from_file(sys, wasm_of("registrar"));
+        let resolver = Program::from_file(sys, wasm_of("resolver"));
+        let universal = Program::from_file(sys, wasm_of("universal-resolver"));
+        let reverse = Program::from_file(sys, wasm_of("reverse-registrar"));
+        let wrapper = Program::from_file(sys, wasm_of("name-wrapper"));
+
+        registry.send_bytes(
+            ADMIN,
+            registry_client::template_factory::io::New::encode_call(registry_client::InitRegistry {
+                admin: actor(ADMIN),
+                root_node: namehash("vara"),
+                root_owner: registrar.id(),
+                hash_scheme: registry_client::HashScheme::Blake2,
+            }),
+        );
+        registrar.send_bytes(
+            ADMIN,
+            registrar_client::template_factory::io::New::encode_call(registrar_client::InitRegistrar {
+                registry: registry.id(),
+                tld_node: namehash("vara"),
+                hash_scheme: registrar_client::HashScheme::Blake2,
+                controller: actor(ADMIN),
+                price_tiers: registrar_client::PriceTiers {
+                    per_year: PRICES_PER_YEAR,
+                    multi_year_discount: 0,
+                },
+                min_commit_age: MIN_COMMIT_AGE,
+                max_commit_age: MAX_COMMIT_AGE,
+                grace_period: GRACE_PERIOD,
+            }),
+        );
+        resolver.send_bytes(
+            ADMIN,
+            resolver_client::template_factory::io::NewWithNameWrapper::encode_call(registry.id(), wrapper.id()),
+        );
+        universal.send_bytes(
+            ADMIN,
+            universal_resolver_client::template_factory::io::New::encode_call(
+                universal_resolver_client::InitUniversalResolver {
+                    registry: registry.id(),
+                    hash_scheme: universal_resolver_client::HashScheme::Blake2,
+                },
+            ),
+        );
+        reverse.send_bytes(
+            ADMIN,
+            reverse_registrar_client::template_factory::io::New::encode_call(
+                reverse_registrar_client::InitReverseRegistrar {
+                    registry: registry.id(),
+                    default_resolver: resolver.id(),
+                    hash_scheme: reverse_registrar_client::HashScheme::Blake2,
+                },
+            ),
+        );
+        wrapper.send_bytes(
+            ADMIN,
+            name_wrapper_client::template_factory::io::New::encode_call(name_wrapper_client::InitNameWrapper {
+                registry: registry.id(),
+                registrar: registrar.id(),
+                tld_node: namehash("vara"),
+                hash_scheme: name_wrapper_client::HashScheme::Blake2,
+            }),
+        );
+        registry.send_bytes(ADMIN, registry_io::SetRootName::encode_call("vara".into()));
+        sys.run_next_block();
+        // The admin briefly controls the registry to create `addr.reverse` for the reverse
+        // registrar, which needs no wider rights.
+        registry.send_bytes(ADMIN, registry_io::AddController::encode_call(actor(ADMIN)));
+        registry.send_bytes(
+            ADMIN,
+            registry_io::SetSubnodeOwnerWithLabel::encode_call(namehash(""), "reverse".into(), actor(ADMIN)),
+        );
+        registry.send_bytes(
+            ADMIN,
+            registry_io::SetSubnodeOwnerWithLabel::encode_call(namehash("reverse"), "addr".into(), reverse.id()),
+        );
+        registry.send_bytes(ADMIN, registry_io::RemoveController::encode_call(actor(ADMIN)));
+        sys.run_next_block();
+
+        Self { sys, registry, registrar, resolver, universal, reverse, wrapper }
+    }
+
+    fn call<A: ActionIo>(&self, program: &Program, from: u64, request: Vec<u8>, value: u128) -> A::Reply {
+        let mid = program.send_bytes_with_value(from, request, value);
+        let res = self.sys.run_next_block();
+        A::decode_reply(reply_of(&res, mid)).expect("failed to decode reply")
+    }
+
+    /// Claim the value the registrar sent to `user`, e.g. a refund, from its mailbox.
+    fn claim_from_registrar(&self, user: u64) {
+        let log = Log::builder().source(self.registrar.id()).dest(user);
+        self.sys.get_mailbox(user).claim_value(log).expect("no value sent to the user");
+    }
+
+    /// Set every price tier to `per_year`. Refunds and withdrawals below the existential
+    /// deposit cannot be transferred, so tests of payouts need more than the default prices.
+    fn set_prices(&self, per_year: u128) {
+        let tiers = registrar_client::PriceTiers { per_year: [per_year; 5], multi_year_discount: 0 };
+        let request = registrar_io::SetPriceTiers::encode_call(tiers);
+        let reply = self.call::<registrar_io::SetPriceTiers>(&self.registrar, ADMIN, request, 0);
+        assert!(reply.is_ok(), "{reply:?}");
+    }
+
+    fn wait(&self, ms: u64) {
+        for _ in 0..ms.div_ceil(BLOCK_MS) {
+            self.sys.run_next_block();
+        }
+    }
+
+    fn price_breakdown(&self, name: &[u8], duration: u64) -> registrar_client::PriceBreakdown {
+        let request = registrar_io::Price::encode_call(name.to_vec(), duration);
+        self.call::<registrar_io::Price>(&self.registrar, ALICE, request, 0)
+            .expect("price of an invalid name")
+    }
+
+    /// Value to attach when registering or renewing `name` for `DURATION`
+    fn price(&self, name: &[u8]) -> u128 {
+        let price = self.price_breakdown(name, DURATION);
+        price.base + price.premium - price.discount + price.expiry_premium
+    }
+
+    fn commit(&self, from: u64, name: &[u8], owner: ActorId, resolver: Option<ActorId>) -> ([u8; 32], [u8; 32]) {
+        // Salted with the block height so that repeated attempts use fresh commitments.
+        let (secret, mut salt) = ([7u8; 32], [0u8; 32]);
+        salt[..4].copy_from_slice(&self.sys.block_height().to_le_bytes());
+        let request = registrar_io::Commit::encode_call(commitment(name, owner, secret, salt, resolver));
+        let reply = self.call::<registrar_io::Commit>(&self.registrar, from, request, 0);
+        assert!(reply.is_ok(), "commit failed: {reply:?}");
+        (secret, salt)
+    }
+
+    fn register(
+        &self,
+        from: u64,
+        name: &[u8],
+        (secret, salt): ([u8; 32], [u8; 32]),
+        resolver: Option<ActorId>,
+        value: u128,
+    ) -> Result<RegistrarEvent, RegistrarError> {
+        let request = registrar_io::Register::encode_call(
+            name.to_vec(),
+            actor(from),
+            DURATION,
+            secret,
+            salt,
+            resolver,
+            resolver.map(|_| actor(from)),
+        );
+        self.call::<registrar_io::Register>(&self.registrar, from, request, value)
+    }
+
+    /// Commit, wait `min_commit_age` and register `name` for `from` at its price.
+    fn claim(&self, from: u64, name: &[u8], resolver: Option<ActorId>) -> Result<RegistrarEvent, RegistrarError> {
+        let secrets = self.commit(from, name, actor(from), resolver);
+        self.wait(MIN_COMMIT_AGE);
+        self.register(from, name, secrets, resolver, self.price(name))
+    }
+
+    fn owner_of(&self, name: &str) -> Option<ActorId> {
+        let request = registry_io::OwnerOf::encode_call(namehash(name));
+        self.call::<registry_io::OwnerOf>(&self.registry, ALICE, request, 0)
+    }
+}
+
+#[test]
+fn register_and_resolve() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let node = namehash("alice.vara");
+
+    let reply = vns.claim(ALICE, b"alice", Some(vns.resolver.id()));
+    assert!(matches!(reply, Ok(RegistrarEvent::NameRegistered { .. })), "{reply:?}");
+
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+    let request = registry_io::ResolverOf::encode_call(node);
+    let resolver = vns.call::<registry_io::ResolverOf>(&vns.registry, ALICE, request, 0);
+    assert_eq!(resolver, Some(vns.resolver.id()));
+
+    // The initial addr record was set by the registrar during registration.
+    let request = resolver_io::AddrOf::encode_call(node);
+    let addr = vns.call::<resolver_io::AddrOf>(&vns.resolver, BOB, request, 0);
+    assert_eq!(addr, Some(actor(ALICE)));
+
+    let request = resolver_io::SetText::encode_call(node, "avatar".into(), "ipfs://alice".into());
+    let reply = vns.call::<resolver_io::SetText>(&vns.resolver, ALICE, request, 0);
+    assert!(reply.is_ok(), "{reply:?}");
+
+    let request = resolver_io::TextOf::encode_call(node, "avatar".into());
+    let text = vns.call::<resolver_io::TextOf>(&vns.resolver, BOB, request, 0);
+    assert_eq!(text.as_deref(), Some("ipfs://alice"));
+}
+
+#[test]
+fn register_without_resolver_mints_subnode() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+
+    assert!(vns.claim(BOB, b"bobby", None).is_ok());
+    assert_eq!(vns.owner_of("bobby.vara"), Some(actor(BOB)));
+}
+
+#[test]
+fn reserved_names_cannot_be_registered() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+
+    let request = registrar_io::ReserveNames::encode_call(vec![b"vara".to_vec()]);
+    let reply = vns.call::<registrar_io::ReserveNames>(&vns.registrar, ADMIN, request, 0);
+    assert!(reply.is_ok(), "{reply:?}");
+
+    let reply = vns.claim(ALICE, b"vara", None);
+    assert!(matches!(reply, Err(RegistrarError::NameReserved)), "{reply:?}");
+    assert_eq!(vns.owner_of("vara.vara"), None);
+}
+
+#[test]
+fn names_are_normalized() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+
+    // The commitment is made over the normalized name, whatever form is registered.
+    let secrets = vns.commit(ALICE, b"alice", actor(ALICE), None);
+    vns.wait(MIN_COMMIT_AGE);
+    let reply = vns.register(ALICE, b"Alice", secrets, None, vns.price(b"alice"));
+    assert!(
+        matches!(&reply, Ok(RegistrarEvent::NameRegistered { name, .. }) if name == b"alice"),
+        "{reply:?}"
+    );
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+
+    let request = registrar_io::Available::encode_call(b"ALICE".to_vec());
+    let reply = vns.call::<registrar_io::Available>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Ok(false)), "{reply:?}");
+
+    let price = |name: &[u8]| {
+        let request = registrar_io::Price::encode_call(name.to_vec(), DURATION);
+        vns.call::<registrar_io::Price>(&vns.registrar, BOB, request, 0)
+    };
+    assert!(matches!(price(b"ab"), Err(RegistrarError::InvalidLabel(LabelError::TooShort))));
+    assert!(matches!(price(b"bob.vara"), Err(RegistrarError::InvalidLabel(LabelError::ContainsDot))));
+    assert!(matches!(
+        price(b"bob by"),
+        Err(RegistrarError::InvalidLabel(LabelError::DisallowedCodePoint(0x20)))
+    ));
+    assert!(matches!(price(b"\xff\xfeab"), Err(RegistrarError::InvalidLabel(LabelError::InvalidUtf8))));
+
+    let secrets = vns.commit(BOB, b"bob by", actor(BOB), None);
+    vns.wait(MIN_COMMIT_AGE);
+    let reply = vns.register(BOB, b"bob by", secrets, None, vns.price(b"bobby"));
+    assert!(matches!(reply, Err(RegistrarError::InvalidLabel(_))), "{reply:?}");
+}
+
+#[test]
+fn confusable_names_are_rejected_unless_allowed() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+
+    // `a1ice` and `аlice` (Cyrillic `а`) look like `alice`.
+    for name in [&b"a1ice"[..], "аlice".as_bytes()] {
+        let request = registrar_io::ConfusableWith::encode_call(name.to_vec());
+        let reply = vns.call::<registrar_io::ConfusableWith>(&vns.registrar, BOB, request, 0);
+        assert!(matches!(&reply, Ok(Some(with)) if with == b"alice"), "{reply:?}");
+
+        let reply = vns.claim(BOB, name, None);
+        assert!(
+            matches!(&reply, Err(RegistrarError::ConfusableName { with }) if with == b"alice"),
+            "{reply:?}"
+        );
+    }
+    let request = registrar_io::ConfusableWith::encode_call(b"alicia".to_vec());
+    let reply = vns.call::<registrar_io::ConfusableWith>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Ok(None)), "{reply:?}");
+
+    let request = registrar_io::SetConfusableAllowed::encode_call(b"a1ice".to_vec(), true);
+    let reply = vns.call::<registrar_io::SetConfusableAllowed>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let request = registrar_io::SetConfusableAllowed::encode_call(b"a1ice".to_vec(), true);
+    let reply = vns.call::<registrar_io::SetConfusableAllowed>(&vns.registrar, ADMIN, request, 0);
+    assert!(reply.is_ok(), "{reply:?}");
+
+    assert!(vns.claim(BOB, b"a1ice", None).is_ok());
+    assert_eq!(vns.owner_of("a1ice.vara"), Some(actor(BOB)));
+    // Reserved names are protected as well.
+    let request = registrar_io::ReserveNames::encode_call(vec![b"vara".to_vec()]);
+    assert!(vns.call::<registrar_io::ReserveNames>(&vns.registrar, ADMIN, request, 0).is_ok());
+    let reply = vns.claim(BOB, "vаra".as_bytes(), None);
+    assert!(matches!(reply, Err(RegistrarError::ConfusableName { .. })), "{reply:?}");
+}
+
+#[test]
+fn prices_follow_length_tiers() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let pro_rata = |per_year: u128, duration: u64| per_year * duration as u128 / YEAR_MS as u128;
+
+    let price = vns.price_breakdown(b"alice", DURATION);
+    assert_eq!(price.base, pro_rata(PRICES_PER_YEAR[4], DURATION));
+    assert_eq!((price.premium, price.discount), (0, 0));
+
+    // Tiers count characters, not bytes: `日本語` is three characters in nine bytes.
+    for name in [&b"bob"[..], "日本語".as_bytes()] {
+        let price = vns.price_breakdown(name, DURATION);
+        assert_eq!(price.base, pro_rata(PRICES_PER_YEAR[4], DURATION));
+        assert_eq!(price.premium, pro_rata(PRICES_PER_YEAR[2] - PRICES_PER_YEAR[4], DURATION));
+    }
+    let price = vns.price_breakdown(b"bobs", YEAR_MS);
+    assert_eq!((price.base, price.premium), (PRICES_PER_YEAR[4], PRICES_PER_YEAR[3] - PRICES_PER_YEAR[4]));
+
+    let tiers = registrar_client::PriceTiers { per_year: PRICES_PER_YEAR, multi_year_discount: 10_001 };
+    let request = registrar_io::SetPriceTiers::encode_call(tiers);
+    let reply = vns.call::<registrar_io::SetPriceTiers>(&vns.registrar, ADMIN, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::InvalidDiscount { discount: 10_001 })), "{reply:?}");
+
+    let tiers = registrar_client::PriceTiers { per_year: PRICES_PER_YEAR, multi_year_discount: 1_000 };
+    let request = registrar_io::SetPriceTiers::encode_call(tiers);
+    let reply = vns.call::<registrar_io::SetPriceTiers>(&vns.registrar, ADMIN, request, 0);
+    assert!(matches!(reply, Ok(RegistrarEvent::PriceTiersSet { .. })), "{reply:?}");
+
+    // 10% off registrations of two years or more only.
+    let price = vns.price_breakdown(b"alice", 2 * YEAR_MS);
+    assert_eq!(price.base, 2 * PRICES_PER_YEAR[4]);
+    assert_eq!(price.discount, price.base / 10);
+    assert_eq!(vns.price_breakdown(b"alice", YEAR_MS).discount, 0);
+}
+
+#[test]
+fn expired_names_are_released_at_a_decaying_premium() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let premium = registrar_client::ExpiryPremium {
+        start: 10_000_000_000_000_000,
+        half_life: 10 * BLOCK_MS,
+        window: 100 * BLOCK_MS,
+    };
+    let request = registrar_io::SetExpiryPremium::encode_call(premium.clone());
+    let reply = vns.call::<registrar_io::SetExpiryPremium>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let request = registrar_io::SetExpiryPremium::encode_call(premium.clone());
+    let reply = vns.call::<registrar_io::SetExpiryPremium>(&vns.registrar, ADMIN, request, 0);
+    assert!(reply.is_ok(), "{reply:?}");
+
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+    assert_eq!(vns.price_breakdown(b"alice", DURATION).expiry_premium, 0);
+    vns.wait(DURATION + GRACE_PERIOD + BLOCK_MS);
+
+    // Just released: close to the start, then halving every half-life.
+    let released = vns.price_breakdown(b"alice", DURATION).expiry_premium;
+    assert!(released > premium.start * 3 / 4 && released <= premium.start, "{released}");
+    vns.wait(premium.half_life);
+    let halved = vns.price_breakdown(b"alice", DURATION).expiry_premium;
+    assert!(halved < released * 3 / 5 && halved > released * 2 / 5, "{released} -> {halved}");
+
+    let secrets = vns.commit(BOB, b"alice", actor(BOB), None);
+    vns.wait(MIN_COMMIT_AGE);
+    let without_premium = vns.price(b"alice") - vns.price_breakdown(b"alice", DURATION).expiry_premium;
+    let reply = vns.register(BOB, b"alice", secrets, None, without_premium);
+    assert!(matches!(reply, Err(RegistrarError::InsufficientPayment { .. })), "{reply:?}");
+    let reply = vns.register(BOB, b"alice", secrets, None, vns.price(b"alice"));
+    assert!(reply.is_ok(), "{reply:?}");
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(BOB)));
+
+    // Nothing is left of the premium once the window has passed.
+    assert!(vns.claim(ALICE, b"carol", None).is_ok());
+    vns.wait(DURATION + GRACE_PERIOD + premium.window);
+    assert_eq!(vns.price_breakdown(b"carol", DURATION).expiry_premium, 0);
+}
+
+#[test]
+fn auctions_go_to_the_highest_bidder_at_the_second_price() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let (bidding, reveal) = (20 * BLOCK_MS, 20 * BLOCK_MS);
+    let request = registrar_io::SetAuctionPeriods::encode_call(bidding, reveal);
+    assert!(vns.call::<registrar_io::SetAuctionPeriods>(&vns.registrar, ADMIN, request, 0).is_ok());
+
+    let request = registrar_io::StartAuction::encode_call(vns_common::labelhash(b"carol"));
+    let reply = vns.call::<registrar_io::StartAuction>(&vns.registrar, BOB, request.clone(), 0);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let reply = vns.call::<registrar_io::StartAuction>(&vns.registrar, ADMIN, request, 0);
+    assert!(matches!(reply, Ok(RegistrarEvent::AuctionStarted { .. })), "{reply:?}");
+
+    let reply = vns.claim(BOB, b"carol", None);
+    assert!(matches!(reply, Err(RegistrarError::NameInAuction)), "{reply:?}");
+
+    // A year of `carol` is the lowest acceptable bid.
+    let reserve = PRICES_PER_YEAR[4];
+    let bids = [(ALICE, 3 * reserve, 4 * reserve), (BOB, 2 * reserve, 2 * reserve)];
+    for (bidder, value, deposit) in bids {
+        let request = registrar_io::BidHash::encode_call(b"carol".to_vec(), actor(bidder), value, [bidder as u8; 32]);
+        let hash = vns.call::<registrar_io::BidHash>(&vns.registrar, bidder, request, 0).expect("valid name");
+        let request = registrar_io::SealBid::encode_call(hash, deposit);
+        let reply = vns.call::<registrar_io::SealBid>(&vns.registrar, bidder, request, deposit);
+        assert!(reply.is_ok(), "{reply:?}");
+    }
+    // Deposits must cover a year of the base price, whatever the bid.
+    let request = registrar_io::MinBidDeposit::encode_call();
+    assert_eq!(vns.call::<registrar_io::MinBidDeposit>(&vns.registrar, BOB, request, 0), reserve);
+    let request = registrar_io::SealBid::encode_call([1; 32], reserve - 1);
+    let reply = vns.call::<registrar_io::SealBid>(&vns.registrar, BOB, request, reserve - 1);
+    assert!(matches!(reply, Err(RegistrarError::DepositTooLow { minimum }) if minimum == reserve), "{reply:?}");
+
+    // A bid that is never revealed stays locked until the auction is over.
+    let unrevealed = [2; 32];
+    let request = registrar_io::SealBid::encode_call(unrevealed, reserve);
+    assert!(vns.call::<registrar_io::SealBid>(&vns.registrar, BOB, request, reserve).is_ok());
+    let reclaim_bid = |from: u64| {
+        let request = registrar_io::ReclaimBid::encode_call(unrevealed);
+        vns.call::<registrar_io::ReclaimBid>(&vns.registrar, from, request, 0)
+    };
+    let reply = reclaim_bid(BOB);
+    assert!(matches!(reply, Err(RegistrarError::BidNotReclaimable)), "{reply:?}");
+    let reveal_bid = |bidder: u64, value: u128| {
+        let request = registrar_io::RevealBid::encode_call(b"carol".to_vec(), value, [bidder as u8; 32]);
+        vns.call::<registrar_io::RevealBid>(&vns.registrar, bidder, request, 0)
+    };
+    let reply = reveal_bid(BOB, 2 * reserve);
+    assert!(matches!(reply, Err(RegistrarError::NotInRevealPeriod)), "{reply:?}");
+
+    vns.wait(bidding);
+    let reply = reveal_bid(BOB, 3 * reserve);
+    assert!(matches!(reply, Err(RegistrarError::NoValidBid)), "{reply:?}");
+    for (bidder, value, _) in bids {
+        let reply = reveal_bid(bidder, value);
+        assert!(matches!(reply, Ok(RegistrarEvent::BidRevealed { accepted: true, .. })), "{reply:?}");
+    }
+    let request = registrar_io::AuctionOf::encode_call(vns_common::labelhash(b"carol"));
+    let auction = vns.call::<registrar_io::AuctionOf>(&vns.registrar, BOB, request, 0).expect("auction in progress");
+    assert_eq!(auction.highest, Some((actor(ALICE), 3 * reserve, 4 * reserve)));
+    assert_eq!(auction.second, 2 * reserve);
+
+    let finalize = || {
+        let request = registrar_io::FinalizeAuction::encode_call(b"carol".to_vec());
+        vns.call::<registrar_io::FinalizeAuction>(&vns.registrar, BOB, request, 0)
+    };
+    let reply = finalize();
+    assert!(matches!(reply, Err(RegistrarError::AuctionNotEnded)), "{reply:?}");
+    let balance = || {
+        let request = registrar_io::QueryState::encode_call(None, 0);
+        vns.call::<registrar_io::QueryState>(&vns.registrar, BOB, request, 0).balance
+    };
+    let balance_before = balance();
+    vns.wait(reveal);
+    let reply = finalize();
+    assert!(
+        matches!(reply, Ok(RegistrarEvent::AuctionFinalized { winner: Some(winner), price, .. })
+            if winner == actor(ALICE) && price == 2 * reserve),
+        "{reply:?}"
+    );
+    assert_eq!(vns.owner_of("carol.vara"), Some(actor(ALICE)));
+
+    // Only the second price is kept; the loser and the rest of the winner's deposit are refunded.
+    assert_eq!(balance() - balance_before, 2 * reserve);
+    let reply = finalize();
+    assert!(matches!(reply, Err(RegistrarError::AuctionNotFound)), "{reply:?}");
+
+    // Only the bidder or the admin can get the unrevealed deposit back, and only to the bidder.
+    let reply = reclaim_bid(ALICE);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let reply = reclaim_bid(ADMIN);
+    assert!(
+        matches!(reply, Ok(RegistrarEvent::BidReclaimed { bidder, deposit, .. }) if bidder == actor(BOB) && deposit == reserve),
+        "{reply:?}"
+    );
+    let reply = reclaim_bid(BOB);
+    assert!(matches!(reply, Err(RegistrarError::NoValidBid)), "{reply:?}");
+}
+
+#[test]
+fn commitments_bind_duration_and_resolver() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let (secret, salt) = ([7u8; 32], [8u8; 32]);
+    let resolver = Some(vns.resolver.id());
+
+    // The query normalizes the name and matches the off-chain computation.
+    let request = registrar_io::MakeCommitment::encode_call(b"Alice".to_vec(), actor(ALICE), secret, salt, DURATION, resolver);
+    let reply = vns.call::<registrar_io::MakeCommitment>(&vns.registrar, BOB, request, 0);
+    assert_eq!(reply.ok(), Some(commitment(b"alice", actor(ALICE), secret, salt, resolver)));
+    let request = registrar_io::CommitmentVersion::encode_call();
+    let version = vns.call::<registrar_io::CommitmentVersion>(&vns.registrar, BOB, request, 0);
+    assert_eq!(version, vns_common::commitment::COMMITMENT_VERSION);
+
+    // Registering with another duration or resolver than committed to is refused.
+    let secrets = vns.commit(ALICE, b"alice", actor(ALICE), resolver);
+    vns.wait(MIN_COMMIT_AGE);
+    let reply = vns.register(ALICE, b"alice", secrets, None, vns.price(b"alice"));
+    assert!(matches!(reply, Err(RegistrarError::NoValidCommitment)), "{reply:?}");
+    let request = registrar_io::Register::encode_call(
+        b"alice".to_vec(),
+        actor(ALICE),
+        2 * DURATION,
+        secrets.0,
+        secrets.1,
+        resolver,
+        None,
+    );
+    let reply = vns.call::<registrar_io::Register>(&vns.registrar, ALICE, request, 2 * vns.price(b"alice"));
+    assert!(matches!(reply, Err(RegistrarError::NoValidCommitment)), "{reply:?}");
+
+    let reply = vns.register(ALICE, b"alice", secrets, resolver, vns.price(b"alice"));
+    assert!(reply.is_ok(), "{reply:?}");
+}
+
+#[test]
+fn commitment_must_age_but_not_expire() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let price = vns.price(b"alice");
+
+    let secrets = vns.commit(ALICE, b"alice", actor(ALICE), None);
+    let reply = vns.register(ALICE, b"alice", secrets, None, price);
+    assert!(matches!(reply, Err(RegistrarError::CommitmentTooNew)), "{reply:?}");
+
+    vns.wait(MAX_COMMIT_AGE + BLOCK_MS);
+    let reply = vns.register(ALICE, b"alice", secrets, None, price);
+    assert!(matches!(reply, Err(RegistrarError::CommitmentExpired)), "{reply:?}");
+}
+
+#[test]
+fn failed_registration_leaves_no_node_behind() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let node = namehash("alice.vara");
+
+    // The universal resolver cannot take the `addr` record, so registration fails after
+    // the node was minted to the registrar.
+    let resolver = Some(vns.universal.id());
+    let secrets = vns.commit(ALICE, b"alice", actor(ALICE), resolver);
+    vns.wait(MIN_COMMIT_AGE);
+    let request = registrar_io::Register::encode_call(
+        b"alice".to_vec(),
+        actor(ALICE),
+        DURATION,
+        secrets.0,
+        secrets.1,
+        resolver,
+        Some(actor(ALICE)),
+    );
+    let reply = vns.call::<registrar_io::Register>(&vns.registrar, ALICE, request, vns.price(b"alice"));
+    assert!(matches!(reply, Err(RegistrarError::RegistryCallFailed)), "{reply:?}");
+
+    assert_eq!(vns.owner_of("alice.vara"), None);
+    let request = registry_io::ResolverOf::encode_call(node);
+    let resolver = vns.call::<registry_io::ResolverOf>(&vns.registry, ALICE, request, 0);
+    assert_eq!(resolver, Some(ActorId::zero()));
+
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+}
+
+#[test]
+fn underpayment_is_rejected_and_refunded() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    // Priced above the most a call can spend on gas, so that balances show the refunds.
+    vns.set_prices(100 * FUNDS);
+    let price = vns.price(b"alice");
+    assert!(price > MAX_FEE);
+
+    let secrets = vns.commit(ALICE, b"alice", actor(ALICE), None);
+    vns.wait(MIN_COMMIT_AGE);
+    let balance = sys.balance_of(ALICE);
+    let reply = vns.register(ALICE, b"alice", secrets, None, price - 1);
+    assert!(matches!(reply, Err(RegistrarError::InsufficientPayment { .. })), "{reply:?}");
+    vns.claim_from_registrar(ALICE);
+    assert!(balance - sys.balance_of(ALICE) < MAX_FEE);
+    assert_eq!(vns.owner_of("alice.vara"), None);
+
+    // The commitment is still usable once the price is paid, and any excess is refunded.
+    let balance = sys.balance_of(ALICE);
+    let reply = vns.register(ALICE, b"alice", secrets, None, 2 * price);
+    assert!(reply.is_ok(), "{reply:?}");
+    vns.claim_from_registrar(ALICE);
+    assert!((price..price + MAX_FEE).contains(&(balance - sys.balance_of(ALICE))));
+}
+
+#[test]
+fn revenue_is_split_and_paid_out() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    vns.set_prices(100 * FUNDS);
+    let price = vns.price(b"alice");
+
+    // Only the admin sets payees.
+    let set_payees = |from| {
+        let request = registrar_io::SetPayees::encode_call(vec![(actor(BOB), 2_500)]);
+        vns.call::<registrar_io::SetPayees>(&vns.registrar, from, request, 0)
+    };
+    let reply = set_payees(ALICE);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let reply = set_payees(ADMIN);
+    assert!(reply.is_ok(), "{reply:?}");
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+
+    // A quarter of the revenue is owed to the payee; the rest is left for the admin.
+    let owed = price / 4;
+    let request = registrar_io::PayeeBalance::encode_call(actor(BOB));
+    assert_eq!(vns.call::<registrar_io::PayeeBalance>(&vns.registrar, BOB, request, 0), owed);
+    let unassigned = || {
+        let request = registrar_io::QueryState::encode_call(None, 0);
+        vns.call::<registrar_io::QueryState>(&vns.registrar, BOB, request, 0).balance
+    };
+    assert_eq!(unassigned(), price - owed);
+
+    // Anyone may release what a payee is owed, and nothing is left to release afterwards.
+    let release = || {
+        let request = registrar_io::Release::encode_call(actor(BOB));
+        vns.call::<registrar_io::Release>(&vns.registrar, ALICE, request, 0)
+    };
+    let balance = sys.balance_of(BOB);
+    let reply = release();
+    assert!(matches!(reply, Ok(RegistrarEvent::Withdrawn { amount, .. }) if amount == owed), "{reply:?}");
+    vns.claim_from_registrar(BOB);
+    assert_eq!(sys.balance_of(BOB), balance + owed);
+    let reply = release();
+    assert!(matches!(reply, Err(RegistrarError::NothingToRelease)), "{reply:?}");
+
+    // Only the admin withdraws, up to the unassigned balance and no less than the
+    // existential deposit.
+    let withdraw = |from, amount| {
+        let request = registrar_io::Withdraw::encode_call(actor(BOB), amount);
+        vns.call::<registrar_io::Withdraw>(&vns.registrar, from, request, 0)
+    };
+    let reply = withdraw(ALICE, 1);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+    let reply = withdraw(ADMIN, price);
+    assert!(matches!(reply, Err(RegistrarError::InsufficientBalance { .. })), "{reply:?}");
+    let reply = withdraw(ADMIN, 1);
+    assert!(matches!(reply, Err(RegistrarError::BelowExistentialDeposit { .. })), "{reply:?}");
+
+    let balance = sys.balance_of(BOB);
+    let reply = withdraw(ADMIN, price - owed);
+    assert!(reply.is_ok(), "{reply:?}");
+    vns.claim_from_registrar(BOB);
+    assert_eq!(sys.balance_of(BOB), balance + price - owed);
+    assert_eq!(unassigned(), 0);
+    let reply = withdraw(ADMIN, 1);
+    assert!(matches!(reply, Err(RegistrarError::InsufficientBalance { .. })), "{reply:?}");
+}
+
+#[test]
+fn names_are_held_through_the_grace_period() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+
+    // Expired but still in grace: only renewal is possible.
+    vns.wait(DURATION + BLOCK_MS);
+    let reply = vns.claim(BOB, b"alice", None);
+    assert!(matches!(reply, Err(RegistrarError::NameNotAvailable)), "{reply:?}");
+
+    vns.wait(GRACE_PERIOD);
+    let reply = vns.claim(BOB, b"alice", None);
+    assert!(reply.is_ok(), "{reply:?}");
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(BOB)));
+}
+
+#[test]
+fn renewal_extends_expiry() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    let Ok(RegistrarEvent::NameRegistered { expires, .. }) = vns.claim(ALICE, b"alice", None) else {
+        std::panic!("registration failed");
+    };
+
+    let request = registrar_io::Renew::encode_call(b"alice".to_vec(), DURATION);
+    let reply = vns.call::<registrar_io::Renew>(&vns.registrar, BOB, request, vns.price(b"alice"));
+    assert!(
+        matches!(reply, Ok(RegistrarEvent::NameRenewed { expires: renewed, .. }) if renewed == expires + DURATION),
+        "{reply:?}"
+    );
+
+    // The registry holds the name until the end of the renewed grace period.
+    let request = registry_io::ExpiryOf::encode_call(namehash("alice.vara"));
+    let registry_expiry = vns.call::<registry_io::ExpiryOf>(&vns.registry, BOB, request, 0);
+    assert_eq!(registry_expiry, Some(expires + DURATION + GRACE_PERIOD));
+}
+
+#[test]
+fn unauthorized_writes_are_rejected() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+    let node = namehash("alice.vara");
+
+    let request = registry_io::SetOwner::encode_call(node, actor(BOB));
+    let reply = vns.call::<registry_io::SetOwner>(&vns.registry, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistryError::NotOwnerOrController)), "{reply:?}");
+
+    let request = resolver_io::SetAddr::encode_call(node, actor(BOB));
+    let reply = vns.call::<resolver_io::SetAddr>(&vns.resolver, BOB, request, 0);
+    assert!(matches!(reply, Err(ResolverError::NotAuthorized)), "{reply:?}");
+
+    let request = resolver_io::SetAddr::encode_call(namehash("nobody.vara"), actor(BOB));
+    let reply = vns.call::<resolver_io::SetAddr>(&vns.resolver, BOB, request, 0);
+    assert!(matches!(reply, Err(ResolverError::NodeHasNoOwner)), "{reply:?}");
+
+    let tiers = registrar_client::PriceTiers { per_year: [0; 5], multi_year_discount: 0 };
+    let request = registrar_io::SetPriceTiers::encode_call(tiers);
+    let reply = vns.call::<registrar_io::SetPriceTiers>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::NotController)), "{reply:?}");
+}
+
+#[test]
+fn resolve_by_name() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+
+    let request = resolver_io::SetText::encode_call(namehash("alice.vara"), "url".into(), "https://alice".into());
+    assert!(vns.call::<resolver_io::SetText>(&vns.resolver, ALICE, request, 0).is_ok());
+
+    let request = universal_io::Resolve::encode_call("alice.vara".into(), vec!["url".into(), "email".into()]);
+    let resolution = vns
+        .call::<universal_io::Resolve>(&vns.universal, BOB, request, 0)
+        .expect("alice.vara resolves");
+    assert_eq!(resolution.node, namehash("alice.vara"));
+    assert_eq!(resolution.resolver, vns.resolver.id());
+    assert_eq!(resolution.addr, Some(actor(ALICE)));
+    assert_eq!(
+        resolution.texts,
+        vec![("url".into(), Some("https://alice".into())), ("email".into(), None)]
+    );
+
+    // Unconfigured subdomains fall back to the closest parent's resolver.
+    let request = registry_io::SetSubnodeOwner::encode_call(namehash("alice.vara"), vns_common::labelhash(b"pay"), actor(ALICE));
+    assert!(vns.call::<registry_io::SetSubnodeOwner>(&vns.registry, ALICE, request, 0).is_ok());
+    let request = universal_io::Resolve::encode_call("pay.alice.vara".into(), vec![]);
+    let resolution = vns
+        .call::<universal_io::Resolve>(&vns.universal, BOB, request, 0)
+        .expect("pay.alice.vara resolves through its parent");
+    assert_eq!(resolution.resolver_node, namehash("alice.vara"));
+    assert_eq!(resolution.addr, None);
+
+    let request = universal_io::Resolve::encode_call("nobody.vara".into(), vec![]);
+    let reply = vns.call::<universal_io::Resolve>(&vns.universal, BOB, request, 0);
+    assert!(matches!(reply, Err(UniversalResolverError::NoResolver)), "{reply:?}");
+
+    // Labels are normalized before hashing, as they were on registration.
+    let request = universal_io::Resolve::encode_call("ALICE.Vara".into(), vec![]);
+    let resolution = vns
+        .call::<universal_io::Resolve>(&vns.universal, BOB, request, 0)
+        .expect("ALICE.Vara resolves as alice.vara");
+    assert_eq!(resolution.node, namehash("alice.vara"));
+    let request = universal_io::Resolve::encode_call("al ice.vara".into(), vec![]);
+    let reply = vns.call::<universal_io::Resolve>(&vns.universal, BOB, request, 0);
+    assert!(
+        matches!(reply, Err(UniversalResolverError::InvalidLabel(universal_resolver_client::LabelError::DisallowedCodePoint(0x20)))),
+        "{reply:?}"
+    );
+}
+
+#[test]
+fn primary_name_requires_forward_resolution() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+
+    let request = reverse_io::SetName::encode_call("al ice.vara".into());
+    let reply = vns.call::<reverse_io::SetName>(&vns.reverse, ALICE, request, 0);
+    assert!(
+        matches!(reply, Err(ReverseRegistrarError::InvalidLabel(reverse_registrar_client::LabelError::DisallowedCodePoint(0x20)))),
+        "{reply:?}"
+    );
+
+    // The name is normalized before it is stored, so it namehashes to alice.vara.
+    let request = reverse_io::SetName::encode_call("Alice.VARA".into());
+    let reply = vns.call::<reverse_io::SetName>(&vns.reverse, ALICE, request, 0);
+    assert!(matches!(&reply, Ok(ReverseRegistrarEvent::NameSet { name, .. }) if name == "alice.vara"), "{reply:?}");
+
+    let request = reverse_io::NodeOf::encode_call(actor(ALICE));
+    let node = vns.call::<reverse_io::NodeOf>(&vns.reverse, BOB, request, 0);
+    let request = registry_io::OwnerOf::encode_call(node);
+    let owner = vns.call::<registry_io::OwnerOf>(&vns.registry, BOB, request, 0);
+    assert_eq!(owner, Some(actor(ALICE)));
+
+    let request = reverse_io::NameOf::encode_call(actor(ALICE));
+    let name = vns.call::<reverse_io::NameOf>(&vns.reverse, BOB, request, 0);
+    assert_eq!(name, Ok(Some("alice.vara".into())));
+
+    // Bob can point his reverse record at alice.vara, but it does not resolve back to him.
+    let request = reverse_io::SetName::encode_call("alice.vara".into());
+    assert!(vns.call::<reverse_io::SetName>(&vns.reverse, BOB, request, 0).is_ok());
+    let request = reverse_io::NameOf::encode_call(actor(BOB));
+    let name = vns.call::<reverse_io::NameOf>(&vns.reverse, BOB, request, 0);
+    assert_eq!(name, Ok(None));
+}
+
+#[test]
+fn multi_coin_addresses() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+    let node = namehash("alice.vara");
+
+    let request = resolver_io::SetAddrForCoin::encode_call(node, coins::ETH, vec![0xab; 20]);
+    let reply = vns.call::<resolver_io::SetAddrForCoin>(&vns.resolver, ALICE, request, 0);
+    assert!(matches!(reply, Ok(ResolverEvent::AddrChanged((_, coins::ETH, _)))), "{reply:?}");
+
+    let request = resolver_io::SetAddrForCoin::encode_call(node, coins::DOT, vec![0xcd; 20]);
+    let reply = vns.call::<resolver_io::SetAddrForCoin>(&vns.resolver, ALICE, request, 0);
+    assert!(matches!(reply, Err(ResolverError::InvalidAddress)), "{reply:?}");
+
+    let request = resolver_io::AddrOfCoin::encode_call(node, coins::ETH);
+    let addr = vns.call::<resolver_io::AddrOfCoin>(&vns.resolver, BOB, request, 0);
+    assert_eq!(addr, Some(vec![0xab; 20]));
+    let request = resolver_io::AddrOfCoin::encode_call(node, coins::DOT);
+    assert_eq!(vns.call::<resolver_io::AddrOfCoin>(&vns.resolver, BOB, request, 0), None);
+
+    // An empty address removes the record.
+    let request = resolver_io::SetAddrForCoin::encode_call(node, coins::ETH, vec![]);
+    assert!(vns.call::<resolver_io::SetAddrForCoin>(&vns.resolver, ALICE, request, 0).is_ok());
+    let request = resolver_io::AddrOfCoin::encode_call(node, coins::ETH);
+    assert_eq!(vns.call::<resolver_io::AddrOfCoin>(&vns.resolver, BOB, request, 0), None);
+
+    // The native `addr` record is the Vara address.
+    let request = resolver_io::SetAddr::encode_call(node, actor(BOB));
+    let reply = vns.call::<resolver_io::SetAddr>(&vns.resolver, ALICE, request, 0);
+    assert!(
+        matches!(&reply, Ok(ResolverEvent::AddrChanged((_, coins::VARA, address))) if address[..] == actor(BOB).as_ref()[..]),
+        "{reply:?}"
+    );
+    let request = resolver_io::AddrOfCoin::encode_call(node, coins::VARA);
+    let addr = vns.call::<resolver_io::AddrOfCoin>(&vns.resolver, BOB, request, 0);
+    assert_eq!(addr, Some(actor(BOB).as_ref().to_vec()));
+}
+
+#[test]
+fn name_tokens_transfer_and_reclaim() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+    let token_id = vns_common::labelhash(b"alice");
+
+    let request = registrar_io::OwnerOfToken::encode_call(token_id);
+    assert_eq!(vns.call::<registrar_io::OwnerOfToken>(&vns.registrar, BOB, request, 0), Some(actor(ALICE)));
+
+    let request = registrar_io::Transfer::encode_call(actor(BOB), token_id);
+    let reply = vns.call::<registrar_io::Transfer>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::NotTokenOwnerOrApproved)), "{reply:?}");
+
+    let request = registrar_io::Transfer::encode_call(actor(BOB), token_id);
+    let reply = vns.call::<registrar_io::Transfer>(&vns.registrar, ALICE, request, 0);
+    assert!(matches!(reply, Ok(RegistrarEvent::Transfer { .. })), "{reply:?}");
+
+    for (holder, balance) in [(ALICE, 0), (BOB, 1)] {
+        let request = registrar_io::BalanceOf::encode_call(actor(holder));
+        assert_eq!(vns.call::<registrar_io::BalanceOf>(&vns.registrar, BOB, request, 0), balance);
+    }
+
+    // The registry is untouched until the new token owner reclaims the node.
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+    let request = registrar_io::Reclaim::encode_call(token_id, actor(BOB));
+    let reply = vns.call::<registrar_io::Reclaim>(&vns.registrar, BOB, request, 0);
+    assert!(reply.is_ok(), "{reply:?}");
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(BOB)));
+
+    // Tokens of expired names cannot be used.
+    vns.wait(DURATION + BLOCK_MS);
+    let request = registrar_io::OwnerOfToken::encode_call(token_id);
+    assert_eq!(vns.call::<registrar_io::OwnerOfToken>(&vns.registrar, BOB, request, 0), None);
+    let request = registrar_io::Transfer::encode_call(actor(ALICE), token_id);
+    let reply = vns.call::<registrar_io::Transfer>(&vns.registrar, BOB, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::TokenNotFound)), "{reply:?}");
+}
+
+#[test]
+fn wrapped_subdomains_cannot_be_taken_back() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+    let alice = namehash("alice.vara");
+
+    // Wrapping needs the wrapper to be approved in the registry by the owner, and for the
+    // name's token in the registrar.
+    let wrap = || {
+        let request = wrapper_io::Wrap::encode_call(namehash("vara"), b"alice".to_vec(), actor(ALICE));
+        vns.call::<wrapper_io::Wrap>(&vns.wrapper, ALICE, request, 0)
+    };
+    let reply = wrap();
+    assert!(matches!(reply, Err(NameWrapperError::RegistrarCallFailed)), "{reply:?}");
+    let request = registry_io::SetApprovalForAll::encode_call(vns.wrapper.id(), true);
+    assert!(vns.call::<registry_io::SetApprovalForAll>(&vns.registry, ALICE, request, 0).is_ok());
+    let request = registrar_io::Approve::encode_call(vns.wrapper.id(), vns_common::labelhash(b"alice"));
+    assert!(vns.call::<registrar_io::Approve>(&vns.registrar, ALICE, request, 0).is_ok());
+    let reply = wrap();
+    assert!(reply.is_ok(), "{reply:?}");
+    assert_eq!(vns.owner_of("alice.vara"), Some(vns.wrapper.id()));
+
+    // The wrapped owner still controls the records of the name.
+    let request = resolver_io::SetAddr::encode_call(alice, actor(BOB));
+    assert!(vns.call::<resolver_io::SetAddr>(&vns.resolver, ALICE, request, 0).is_ok());
+
+    // Giving up control of a subdomain requires the parent to be locked first.
+    let request = wrapper_io::SetSubnodeOwner::encode_call(alice, b"bob".to_vec(), actor(BOB), fuses::PARENT_CANNOT_CONTROL, u64::MAX);
+    let reply = vns.call::<wrapper_io::SetSubnodeOwner>(&vns.wrapper, ALICE, request, 0);
+    assert!(matches!(reply, Err(NameWrapperError::ParentCanUnwrap)), "{reply:?}");
+
+    let request = wrapper_io::SetFuses::encode_call(alice, fuses::CANNOT_UNWRAP);
+    assert!(vns.call::<wrapper_io::SetFuses>(&vns.wrapper, ALICE, request, 0).is_ok());
+    let request = wrapper_io::SetSubnodeOwner::encode_call(alice, b"bob".to_vec(), actor(BOB), fuses::PARENT_CANNOT_CONTROL, u64::MAX);
+    assert!(vns.call::<wrapper_io::SetSubnodeOwner>(&vns.wrapper, ALICE, request, 0).is_ok());
+
+    let request = wrapper_io::SetSubnodeOwner::encode_call(alice, b"bob".to_vec(), actor(ALICE), 0, u64::MAX);
+    let reply = vns.call::<wrapper_io::SetSubnodeOwner>(&vns.wrapper, ALICE, request, 0);
+    assert!(
+        matches!(reply, Err(NameWrapperError::FuseBurned { fuse }) if fuse == fuses::PARENT_CANNOT_CONTROL),
+        "{reply:?}"
+    );
+    let request = wrapper_io::OwnerOf::encode_call(namehash("bob.alice.vara"));
+    assert_eq!(vns.call::<wrapper_io::OwnerOf>(&vns.wrapper, BOB, request, 0), Some(actor(BOB)));
+
+    let request = wrapper_io::Unwrap::encode_call(alice, actor(ALICE));
+    let reply = vns.call::<wrapper_io::Unwrap>(&vns.wrapper, ALICE, request, 0);
+    assert!(matches!(reply, Err(NameWrapperError::FuseBurned { .. })), "{reply:?}");
+
+    // Fuses only hold until the name expires.
+    vns.wait(DURATION + BLOCK_MS);
+    let request = wrapper_io::AllFusesBurned::encode_call(namehash("bob.alice.vara"), fuses::PARENT_CANNOT_CONTROL);
+    assert!(!vns.call::<wrapper_io::AllFusesBurned>(&vns.wrapper, BOB, request, 0));
+
+    // Once the registry releases the name, the wrapper drops it.
+    vns.wait(GRACE_PERIOD);
+    let request = wrapper_io::SetResolver::encode_call(alice, vns.resolver.id());
+    let reply = vns.call::<wrapper_io::SetResolver>(&vns.wrapper, ALICE, request, 0);
+    assert!(matches!(reply, Err(NameWrapperError::NotWrapped)), "{reply:?}");
+    let request = wrapper_io::OwnerOf::encode_call(alice);
+    assert_eq!(vns.call::<wrapper_io::OwnerOf>(&vns.wrapper, ALICE, request, 0), None);
+}
+
+#[test]
+fn wrapped_names_keep_their_token() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+    let alice = namehash("alice.vara");
+    let token_id = vns_common::labelhash(b"alice");
+    let request = registry_io::SetApprovalForAll::encode_call(vns.wrapper.id(), true);
+    assert!(vns.call::<registry_io::SetApprovalForAll>(&vns.registry, ALICE, request, 0).is_ok());
+    let request = registrar_io::SetApprovalForAll::encode_call(vns.wrapper.id(), true);
+    assert!(vns.call::<registrar_io::SetApprovalForAll>(&vns.registrar, ALICE, request, 0).is_ok());
+    let wrap = || {
+        let request = wrapper_io::Wrap::encode_call(namehash("vara"), b"alice".to_vec(), actor(ALICE));
+        let reply = vns.call::<wrapper_io::Wrap>(&vns.wrapper, ALICE, request, 0);
+        assert!(reply.is_ok(), "{reply:?}");
+    };
+    let token_owner = || {
+        let request = registrar_io::OwnerOfToken::encode_call(token_id);
+        vns.call::<registrar_io::OwnerOfToken>(&vns.registrar, BOB, request, 0)
+    };
+
+    // The wrapper holds the token while the name is wrapped, and hands it on when unwrapping.
+    wrap();
+    assert_eq!(token_owner(), Some(vns.wrapper.id()));
+    let request = wrapper_io::Unwrap::encode_call(alice, actor(ALICE));
+    assert!(vns.call::<wrapper_io::Unwrap>(&vns.wrapper, ALICE, request, 0).is_ok());
+    assert_eq!(token_owner(), Some(actor(ALICE)));
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+
+    // Once the name cannot be unwrapped, its former token owner cannot reclaim it either.
+    wrap();
+    let request = wrapper_io::SetFuses::encode_call(alice, fuses::CANNOT_UNWRAP);
+    assert!(vns.call::<wrapper_io::SetFuses>(&vns.wrapper, ALICE, request, 0).is_ok());
+    let request = registrar_io::Reclaim::encode_call(token_id, actor(ALICE));
+    let reply = vns.call::<registrar_io::Reclaim>(&vns.registrar, ALICE, request, 0);
+    assert!(matches!(reply, Err(RegistrarError::NotTokenOwnerOrApproved)), "{reply:?}");
+    assert_eq!(vns.owner_of("alice.vara"), Some(vns.wrapper.id()));
+}
+
+#[test]
+fn expired_holders_lose_control() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", Some(vns.resolver.id())).is_ok());
+    let node = namehash("alice.vara");
+    let set_text = |from| {
+        let request = resolver_io::SetText::encode_call(node, "url".into(), "https://alice".into());
+        vns.call::<resolver_io::SetText>(&vns.resolver, from, request, 0)
+    };
+
+    // Operators edit the records of all of their owner's names.
+    let request = resolver_io::SetApprovalForAll::encode_call(actor(ADMIN), true);
+    assert!(vns.call::<resolver_io::SetApprovalForAll>(&vns.resolver, ALICE, request, 0).is_ok());
+    let request = resolver_io::IsApprovedForAll::encode_call(actor(ALICE), actor(ADMIN));
+    assert!(vns.call::<resolver_io::IsApprovedForAll>(&vns.resolver, BOB, request, 0));
+    let reply = set_text(ADMIN);
+    assert!(reply.is_ok(), "{reply:?}");
+
+    // Still owned during the grace period.
+    vns.wait(DURATION + BLOCK_MS);
+    assert_eq!(vns.owner_of("alice.vara"), Some(actor(ALICE)));
+
+    vns.wait(GRACE_PERIOD);
+    assert_eq!(vns.owner_of("alice.vara"), None);
+    let reply = set_text(ALICE);
+    assert!(matches!(reply, Err(ResolverError::NodeHasNoOwner)), "{reply:?}");
+    let request = registry_io::SetResolver::encode_call(node, actor(ALICE));
+    let reply = vns.call::<registry_io::SetResolver>(&vns.registry, ALICE, request, 0);
+    assert!(matches!(reply, Err(RegistryError::NotOwnerOrController)), "{reply:?}");
+
+    // Operators of the previous owner cannot edit the name once someone else registers it.
+    assert!(vns.claim(BOB, b"alice", Some(vns.resolver.id())).is_ok());
+    let reply = set_text(ADMIN);
+    assert!(matches!(reply, Err(ResolverError::NotAuthorized)), "{reply:?}");
+    let reply = set_text(BOB);
+    assert!(reply.is_ok(), "{reply:?}");
+}
+
+#[test]
+fn registrar_state_is_paginated() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    for name in [b"alice".as_slice(), b"bobby", b"carol"] {
+        assert!(vns.claim(ALICE, name, None).is_ok());
+    }
+
+    let request = registrar_io::QueryState::encode_call(None, 2);
+    let state = vns.call::<registrar_io::QueryState>(&vns.registrar, BOB, request, 0);
+    let names: Vec<_> = state.expires.iter().map(|(name, _)| name.clone()).collect();
+    assert_eq!(names, vec![b"alice".to_vec(), b"bobby".to_vec()]);
+    assert_eq!(state.next_cursor, Some(b"bobby".to_vec()));
+
+    let request = registrar_io::ExpiriesPage::encode_call(state.next_cursor, 2);
+    let (page, cursor) = vns.call::<registrar_io::ExpiriesPage>(&vns.registrar, BOB, request, 0);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].0, b"carol".to_vec());
+    assert_eq!(cursor, None);
+
+    let request = registry_io::OwnersPage::encode_call(None, 10);
+    let (owners, _) = vns.call::<registry_io::OwnersPage>(&vns.registry, BOB, request, 0);
+    assert!(owners.contains(&(namehash("carol.vara"), actor(ALICE))));
+}
+
+#[test]
+fn names_are_listed_per_owner() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    for name in [b"alice".as_slice(), b"carol"] {
+        assert!(vns.claim(ALICE, name, None).is_ok());
+    }
+    assert!(vns.claim(BOB, b"bobby", None).is_ok());
+
+    let request = registry_io::NodesOf::encode_call(actor(ALICE), None, 10);
+    let (mut nodes, cursor) = vns.call::<registry_io::NodesOf>(&vns.registry, BOB, request, 0);
+    nodes.sort();
+    let mut expected = vec![namehash("alice.vara"), namehash("carol.vara")];
+    expected.sort();
+    assert_eq!((nodes, cursor), (expected, None));
+
+    let request = registrar_io::LabelsOf::encode_call(actor(ALICE), None, 1);
+    let (labels, cursor) = vns.call::<registrar_io::LabelsOf>(&vns.registrar, BOB, request, 0);
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].0, b"alice".to_vec());
+    let request = registrar_io::LabelsOf::encode_call(actor(ALICE), cursor, 1);
+    let (labels, cursor) = vns.call::<registrar_io::LabelsOf>(&vns.registrar, BOB, request, 0);
+    assert_eq!((labels[0].0.clone(), cursor), (b"carol".to_vec(), None));
+
+    // Transferring a name's token moves it to the new holder's list.
+    let request = registrar_io::Transfer::encode_call(actor(BOB), vns_common::labelhash(b"carol"));
+    assert!(vns.call::<registrar_io::Transfer>(&vns.registrar, ALICE, request, 0).is_ok());
+    let request = registrar_io::LabelsOf::encode_call(actor(BOB), None, 10);
+    let (labels, _) = vns.call::<registrar_io::LabelsOf>(&vns.registrar, BOB, request, 0);
+    let names: Vec<_> = labels.into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec![b"bobby".to_vec(), b"carol".to_vec()]);
+}
+
+#[test]
+fn subnode_tree_is_enumerable() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+    let alice = namehash("alice.vara");
+    for label in [b"pay".as_slice(), b"mail"] {
+        let request = registry_io::SetSubnodeOwner::encode_call(alice, vns_common::labelhash(label), actor(ALICE));
+        assert!(vns.call::<registry_io::SetSubnodeOwner>(&vns.registry, ALICE, request, 0).is_ok());
+    }
+
+    let request = registry_io::ChildrenOf::encode_call(alice, None, 10);
+    let (children, cursor) = vns.call::<registry_io::ChildrenOf>(&vns.registry, BOB, request, 0);
+    let mut nodes: Vec<_> = children.into_iter().map(|(_, node)| node).collect();
+    nodes.sort();
+    let mut expected = vec![namehash("pay.alice.vara"), namehash("mail.alice.vara")];
+    expected.sort();
+    assert_eq!((nodes, cursor), (expected, None));
+
+    let request = registry_io::ParentOf::encode_call(namehash("pay.alice.vara"));
+    let parent = vns.call::<registry_io::ParentOf>(&vns.registry, BOB, request, 0);
+    assert_eq!(parent, Some((alice, vns_common::labelhash(b"pay"))));
+    let request = registry_io::ParentOf::encode_call(alice);
+    let parent = vns.call::<registry_io::ParentOf>(&vns.registry, BOB, request, 0);
+    assert_eq!(parent, Some((namehash("vara"), vns_common::labelhash(b"alice"))));
+}
+
+#[test]
+fn nodes_are_named_from_stored_preimages() {
+    let sys = System::new();
+    let vns = Vns::deploy(&sys);
+    assert!(vns.claim(ALICE, b"alice", None).is_ok());
+    let name_of = |node| {
+        let request = registry_io::NameOfNode::encode_call(node);
+        vns.call::<registry_io::NameOfNode>(&vns.registry, BOB, request, 0)
+    };
+    assert_eq!(name_of(namehash("alice.vara")), Some("alice.vara".into()));
+
+    let request = registry_io::SetSubnodeOwnerWithLabel::encode_call(namehash("alice.vara"), "pay".into(), actor(ALICE));
+    assert!(vns.call::<registry_io::SetSubnodeOwnerWithLabel>(&vns.registry, ALICE, request, 0).is_ok());
+    assert_eq!(name_of(namehash("pay.alice.vara")), Some("pay.alice.vara".into()));
+
+    // Subnodes created from a bare label hash stay unnamed until their preimage is added.
+    let request = registry_io::SetSubnodeOwner::encode_call(namehash("alice.vara"), vns_common::labelhash(b"mail"), actor(ALICE));
+    assert!(vns.call::<registry_io::SetSubnodeOwner>(&vns.registry, ALICE, request, 0).is_ok());
+    assert_eq!(name_of(namehash("mail.alice.vara")), None);
+    let add_preimage = |label: &str| {
+        let request = registry_io::AddPreimage::encode_call(namehash("alice.vara"), label.into());
+        vns.call::<registry_io::AddPreimage>(&vns.registry, BOB, request, 0)
+    };
+    assert!(add_preimage("mail").is_ok());
+    assert_eq!(name_of(namehash("mail.alice.vara")), Some("mail.alice.vara".into()));
+
+    let reply = add_preimage("a.b");
+    assert!(matches!(reply, Err(RegistryError::InvalidLabel)), "{reply:?}");
+    // Only labels of existing subnodes are stored.
+    let reply = add_preimage("www");
+    assert!(matches!(reply, Err(RegistryError::UnknownLabel)), "{reply:?}");
+}
@@ -0,0 +1,9 @@
+/// Client of the registry program, generated from `idls/registry.idl` by build.rs
+pub mod registry_client {
+    include!(concat!(env!("OUT_DIR"), "/registry_client.rs"));
+}
+
+/// Client of the resolver program, generated from `idls/resolver.idl` by build.rs
+pub mod resolver_client {
+    include!(concat!(env!("OUT_DIR"), "/resolver_client.rs"));
+}
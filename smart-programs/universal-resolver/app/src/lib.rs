@@ -0,0 +1,22 @@
+#![no_std]
+use sails_rs::prelude::*;
+pub mod clients;
+pub mod services;
+
+use services::service::{Service, InitUniversalResolver};
+
+pub struct Program;
+
+#[program]
+impl Program {
+    /// Construct a new UniversalResolver program on top of an existing registry.
+    pub fn new(init: InitUniversalResolver) -> Self {
+        Service::seed(init);
+        Self
+    }
+
+    #[export(route = "Service")]
+    pub fn service(&self) -> Service {
+        Service::new()
+    }
+}
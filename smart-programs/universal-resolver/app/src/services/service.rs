@@ -0,0 +1,198 @@
+#![allow(static_mut_refs)]
+
+use sails_rs::{
+    prelude::*,
+    gstd::msg,
+};
+use sails_rs::calls::ActionIo;
+
+use crate::clients::registry_client::service::io as registry_io;
+use crate::clients::resolver_client::service::io as resolver_io;
+
+pub use vns_common::{HashScheme, Label, Node};
+use vns_common::normalize::{normalize_any_length, LabelError};
+
+const MAX_NAME_LENGTH: usize = 1024;
+const MAX_TEXT_KEYS: usize = 16;
+
+/// Records of a name, as returned by `resolve`
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Resolution {
+    /// Namehash of the resolved name
+    pub node: Node,
+    /// Resolver the records were read from
+    pub resolver: ActorId,
+    /// Node the resolver was found on: `node` itself, or the closest parent with a resolver
+    pub resolver_node: Node,
+    pub addr: Option<ActorId>,
+    /// Requested text keys, in request order, with their values
+    pub texts: Vec<(String, Option<String>)>,
+    pub contenthash: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UniversalResolverError {
+    InvalidName,
+    TooManyKeys,
+    NoResolver,
+    RegistryCallFailed,
+    ResolverCallFailed,
+    /// A label of the name does not normalize; see `vns_common::normalize`
+    InvalidLabel(LabelError),
+}
+
+#[derive(Debug, Encode, Decode, Clone, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitUniversalResolver {
+    pub registry: ActorId,
+    /// Must match the registry's `hash_scheme`, as names are hashed here.
+    pub hash_scheme: HashScheme,
+}
+
+#[derive(Debug, Default)]
+pub struct UniversalResolverState {
+    pub registry: ActorId,
+    pub hash_scheme: HashScheme,
+}
+
+static mut UNIVERSAL_RESOLVER_STATE: Option<UniversalResolverState> = None;
+
+impl UniversalResolverState {
+    pub fn state_ref() -> &'static UniversalResolverState {
+        let s = unsafe { UNIVERSAL_RESOLVER_STATE.as_ref() };
+        debug_assert!(s.is_some(), "Universal resolver state not initialized");
+        unsafe { s.unwrap_unchecked() }
+    }
+}
+
+/// Send an encoded call to `program` and decode its reply.
+/// Returns `None` if the message could not be sent or the program rejected the call.
+async fn call<A: ActionIo>(program: ActorId, request: Vec<u8>) -> Option<A::Reply> {
+    let bytes_reply = msg::send_bytes_for_reply(program, request, 0, 0)
+        .ok()?
+        .await
+        .ok()?;
+    A::decode_reply(bytes_reply).ok()
+}
+
+/// Labels of a full dotted name, each normalized as the registrar does. Any length of
+/// label is accepted, as the registrar's minimum only applies to new registrations.
+fn normalize_name(name: &str) -> Result<Vec<Label>, UniversalResolverError> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH || name.split('.').any(str::is_empty) {
+        return Err(UniversalResolverError::InvalidName);
+    }
+    name.split('.')
+        .map(|label| normalize_any_length(label.as_bytes()).map_err(UniversalResolverError::InvalidLabel))
+        .collect()
+}
+
+/// Nodes of the name made of `labels` and each of its parents, most specific first:
+/// `alice.vara` gives the nodes of `alice.vara` and `vara`.
+fn name_and_parent_nodes(scheme: HashScheme, labels: &[Label]) -> Vec<Node> {
+    let mut nodes: Vec<Node> = labels
+        .iter()
+        .rev()
+        .scan(vns_common::root_node(), |parent, label| {
+            *parent = scheme.subnode(*parent, scheme.labelhash(label));
+            Some(*parent)
+        })
+        .collect();
+    nodes.reverse();
+    nodes
+}
+
+#[derive(Default)]
+pub struct Service;
+
+impl Service {
+    pub fn seed(init: InitUniversalResolver) {
+        unsafe {
+            UNIVERSAL_RESOLVER_STATE = Some(UniversalResolverState {
+                registry: init.registry,
+                hash_scheme: init.hash_scheme,
+            });
+        }
+    }
+}
+
+#[sails_rs::service]
+impl Service {
+    pub fn new() -> Self { Self }
+
+    /// Resolve a full dotted name such as `alice.vara` to its `addr`, `contenthash` and the
+    /// requested `text` records. If the name itself has no resolver, the closest parent's
+    /// resolver is asked for the name's records.
+    ///
+    /// Each label is normalized first, so `Alice.VARA` resolves like `alice.vara`; names
+    /// with a label that cannot be normalized are rejected with `InvalidLabel`.
+    ///
+    /// Sent as a message rather than read as a query: it needs replies from the registry
+    /// and the resolver.
+    pub async fn resolve(
+        &mut self,
+        name: String,
+        text_keys: Vec<String>,
+    ) -> Result<Resolution, UniversalResolverError> {
+        let labels = normalize_name(&name)?;
+        if text_keys.len() > MAX_TEXT_KEYS {
+            return Err(UniversalResolverError::TooManyKeys);
+        }
+        let s = UniversalResolverState::state_ref();
+        let (registry, scheme) = (s.registry, s.hash_scheme);
+
+        let nodes = name_and_parent_nodes(scheme, &labels);
+        let node = nodes[0];
+
+        let mut found = None;
+        for candidate in nodes {
+            let request = registry_io::ResolverOf::encode_call(candidate);
+            let resolver = call::<registry_io::ResolverOf>(registry, request)
+                .await
+                .ok_or(UniversalResolverError::RegistryCallFailed)?;
+            if let Some(resolver) = resolver {
+                found = Some((resolver, candidate));
+                break;
+            }
+        }
+        let (resolver, resolver_node) = found.ok_or(UniversalResolverError::NoResolver)?;
+
+        let request = resolver_io::AddrOf::encode_call(node);
+        let addr = call::<resolver_io::AddrOf>(resolver, request)
+            .await
+            .ok_or(UniversalResolverError::ResolverCallFailed)?;
+
+        let mut texts = Vec::with_capacity(text_keys.len());
+        for key in text_keys {
+            let request = resolver_io::TextOf::encode_call(node, key.clone());
+            let value = call::<resolver_io::TextOf>(resolver, request)
+                .await
+                .ok_or(UniversalResolverError::ResolverCallFailed)?;
+            texts.push((key, value));
+        }
+
+        let request = resolver_io::ContenthashOf::encode_call(node);
+        let contenthash = call::<resolver_io::ContenthashOf>(resolver, request)
+            .await
+            .ok_or(UniversalResolverError::ResolverCallFailed)?;
+
+        Ok(Resolution { node, resolver, resolver_node, addr, texts, contenthash })
+    }
+
+    /// QUERY: Namehash of a full dotted name under the registry's hash scheme, after
+    /// normalizing its labels as `resolve` does
+    pub fn namehash(&self, name: String) -> Result<Node, UniversalResolverError> {
+        let labels = normalize_name(&name)?;
+        let scheme = UniversalResolverState::state_ref().hash_scheme;
+        Ok(name_and_parent_nodes(scheme, &labels)[0])
+    }
+
+    /// QUERY: Registry this resolver reads from
+    pub fn registry(&self) -> ActorId {
+        UniversalResolverState::state_ref().registry
+    }
+}
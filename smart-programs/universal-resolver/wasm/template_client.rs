@@ -0,0 +1,222 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct TemplateFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> TemplateFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::TemplateFactory for TemplateFactory<R> {
+    type Args = R::Args;
+    /// Construct a new UniversalResolver program on top of an existing registry.
+    fn new(&self, init: InitUniversalResolver) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, template_factory::io::New>::new(self.remoting.clone(), init)
+    }
+}
+
+pub mod template_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(init: super::InitUniversalResolver) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&init)
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = super::InitUniversalResolver;
+            type Reply = ();
+        }
+    }
+}
+pub struct Service<R> {
+    remoting: R,
+}
+impl<R> Service<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::Service for Service<R> {
+    type Args = R::Args;
+    /// Resolve a full dotted name such as `alice.vara` to its `addr`, `contenthash` and the
+    /// requested `text` records. If the name itself has no resolver, the closest parent's
+    /// resolver is asked for the name's records.
+    ///
+    /// Each label is normalized first, so `Alice.VARA` resolves like `alice.vara`; names
+    /// with a label that cannot be normalized are rejected with `InvalidLabel`.
+    ///
+    /// Sent as a message rather than read as a query: it needs replies from the registry
+    /// and the resolver.
+    fn resolve(
+        &mut self,
+        name: String,
+        text_keys: Vec<String>,
+    ) -> impl Call<Output = Result<Resolution, UniversalResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Resolve>::new(self.remoting.clone(), (name, text_keys))
+    }
+    /// QUERY: Namehash of a full dotted name under the registry's hash scheme, after
+    /// normalizing its labels as `resolve` does
+    fn namehash(
+        &self,
+        name: String,
+    ) -> impl Query<Output = Result<U256, UniversalResolverError>, Args = R::Args> {
+        RemotingAction::<_, service::io::Namehash>::new(self.remoting.clone(), name)
+    }
+    /// QUERY: Registry this resolver reads from
+    fn registry(&self) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, service::io::Registry>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct Resolve(());
+        impl Resolve {
+            #[allow(dead_code)]
+            pub fn encode_call(name: String, text_keys: Vec<String>) -> Vec<u8> {
+                <Resolve as ActionIo>::encode_call(&(name, text_keys))
+            }
+        }
+        impl ActionIo for Resolve {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 28, 82, 101, 115, 111, 108, 118, 101,
+            ];
+            type Params = (String, Vec<String>);
+            type Reply = Result<super::Resolution, super::UniversalResolverError>;
+        }
+        pub struct Namehash(());
+        impl Namehash {
+            #[allow(dead_code)]
+            pub fn encode_call(name: String) -> Vec<u8> {
+                <Namehash as ActionIo>::encode_call(&name)
+            }
+        }
+        impl ActionIo for Namehash {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 78, 97, 109, 101, 104, 97, 115, 104,
+            ];
+            type Params = String;
+            type Reply = Result<U256, super::UniversalResolverError>;
+        }
+        pub struct Registry(());
+        impl Registry {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Registry as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Registry {
+            const ROUTE: &'static [u8] = &[
+                28, 83, 101, 114, 118, 105, 99, 101, 32, 82, 101, 103, 105, 115, 116, 114, 121,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct InitUniversalResolver {
+    pub registry: ActorId,
+    /// Must match the registry's `hash_scheme`, as names are hashed here.
+    pub hash_scheme: HashScheme,
+}
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+/// Records of a name, as returned by `resolve`
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Resolution {
+    /// Namehash of the resolved name
+    pub node: U256,
+    /// Resolver the records were read from
+    pub resolver: ActorId,
+    /// Node the resolver was found on: `node` itself, or the closest parent with a resolver
+    pub resolver_node: U256,
+    pub addr: Option<ActorId>,
+    /// Requested text keys, in request order, with their values
+    pub texts: Vec<(String, Option<String>)>,
+    pub contenthash: Option<Vec<u8>>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum UniversalResolverError {
+    InvalidName,
+    TooManyKeys,
+    NoResolver,
+    RegistryCallFailed,
+    ResolverCallFailed,
+    /// A label of the name does not normalize; see `vns_common::normalize`
+    InvalidLabel(LabelError),
+}
+/// Why a label was rejected.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LabelError {
+    /// The label is not valid UTF-8.
+    InvalidUtf8,
+    /// The label has fewer than `MIN_LABEL_CHARS` characters.
+    TooShort,
+    /// The label contains a dot, or a character normalizing to one.
+    ContainsDot,
+    /// The label contains a code point that cannot appear in a name.
+    DisallowedCodePoint(u32),
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait TemplateFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, init: InitUniversalResolver) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait Service {
+        type Args;
+        fn resolve(
+            &mut self,
+            name: String,
+            text_keys: Vec<String>,
+        ) -> impl Call<Output = Result<Resolution, UniversalResolverError>, Args = Self::Args>;
+        fn namehash(
+            &self,
+            name: String,
+        ) -> impl Query<Output = Result<U256, UniversalResolverError>, Args = Self::Args>;
+        fn registry(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+    }
+}
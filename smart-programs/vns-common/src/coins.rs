@@ -0,0 +1,43 @@
+//! SLIP-44 coin types and the binary address formats the resolver accepts for them.
+//!
+//! Addresses are stored in their binary form rather than as display strings, as in
+//! ENSIP-9: a Bitcoin address as its `scriptPubkey`, an Ethereum address as its 20 bytes
+//! and a Substrate SS58 address as the 32-byte account id it encodes.
+
+/// Bitcoin
+pub const BTC: u32 = 0;
+/// Litecoin
+pub const LTC: u32 = 2;
+/// Ethereum
+pub const ETH: u32 = 60;
+/// Polkadot
+pub const DOT: u32 = 354;
+/// Kusama
+pub const KSM: u32 = 434;
+/// Vara Network: the resolver's native `addr` records
+pub const VARA: u32 = 4884;
+
+/// Upper bound on the length of any stored address.
+pub const MAX_ADDRESS_LENGTH: usize = 128;
+
+/// Whether `address` is a well-formed binary address for `coin_type`. Coin types without
+/// a known format only need to be non-empty and at most `MAX_ADDRESS_LENGTH` bytes.
+pub fn is_valid_address(coin_type: u32, address: &[u8]) -> bool {
+    match coin_type {
+        BTC | LTC => is_valid_script_pubkey(address),
+        ETH => address.len() == 20,
+        DOT | KSM | VARA => address.len() == 32,
+        _ => !address.is_empty() && address.len() <= MAX_ADDRESS_LENGTH,
+    }
+}
+
+/// Standard output scripts: P2PKH, P2SH and segwit v0/v1 (P2WPKH, P2WSH, P2TR).
+fn is_valid_script_pubkey(script: &[u8]) -> bool {
+    match script {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] => hash.len() == 20,
+        [0xa9, 0x14, hash @ .., 0x87] => hash.len() == 20,
+        [0x00, 0x14, program @ ..] => program.len() == 20,
+        [0x00, 0x20, program @ ..] | [0x51, 0x20, program @ ..] => program.len() == 32,
+        _ => false,
+    }
+}
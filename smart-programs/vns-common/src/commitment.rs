@@ -0,0 +1,37 @@
+//! Commitments made before registering a name through commit-reveal.
+//!
+//! A commitment is the `blake2_256` hash of the SCALE encoding of
+//! `(COMMITMENT_VERSION, name, owner, secret, salt, duration, resolver)`:
+//!
+//! | field      | type             | encoding                                   |
+//! |------------|------------------|--------------------------------------------|
+//! | version    | `u8`             | 1 byte                                     |
+//! | `name`     | `Vec<u8>`        | compact length, then the normalized label  |
+//! | `owner`    | `[u8; 32]`       | 32 bytes                                   |
+//! | `secret`   | `[u8; 32]`       | 32 bytes                                   |
+//! | `salt`     | `[u8; 32]`       | 32 bytes                                   |
+//! | `duration` | `u64`            | 8 bytes, little-endian                     |
+//! | `resolver` | `Option<[u8;32]>`| `0x00`, or `0x01` then 32 bytes            |
+//!
+//! Committing to the duration and resolver keeps them from being changed by whoever sees
+//! the registration before it is included. The version changes whenever the layout does.
+
+use parity_scale_codec::Encode;
+
+use crate::blake2_256;
+
+/// Version of the commitment layout accepted by the registrar.
+pub const COMMITMENT_VERSION: u8 = 1;
+
+/// Commitment to registering `name` (normalized, see `normalize`) for `owner`, for
+/// `duration` ms with `resolver`.
+pub fn make_commitment(
+    name: &[u8],
+    owner: &[u8; 32],
+    secret: &[u8; 32],
+    salt: &[u8; 32],
+    duration: u64,
+    resolver: Option<&[u8; 32]>,
+) -> [u8; 32] {
+    blake2_256(&(COMMITMENT_VERSION, name, owner, secret, salt, duration, resolver).encode())
+}
@@ -0,0 +1,26 @@
+//! Confusable detection, following the skeleton algorithm of Unicode TR39.
+//!
+//! Two labels that look alike, such as `paypal` and `pаypal` (Cyrillic `а`) or `g00gle`
+//! and `google`, have the same skeleton. Skeletons are only compared, never registered.
+
+use alloc::string::String;
+use unicode_security::confusable_detection;
+
+use crate::Label;
+
+/// TR39 skeleton of a normalized label, lowercased so that it compares with labels as
+/// registered (the confusables data maps e.g. `0` to `O`).
+///
+/// Returns the label's bytes unchanged if they are not valid UTF-8.
+pub fn skeleton(label: &[u8]) -> Label {
+    let Ok(label) = core::str::from_utf8(label) else {
+        return label.into();
+    };
+    let skeleton: String = confusable_detection::skeleton(label).flat_map(char::to_lowercase).collect();
+    skeleton.into_bytes()
+}
+
+/// Whether two labels look alike. A label is confusable with itself.
+pub fn is_confusable(a: &[u8], b: &[u8]) -> bool {
+    skeleton(a) == skeleton(b)
+}
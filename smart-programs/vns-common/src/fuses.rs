@@ -0,0 +1,24 @@
+//! Permission fuses of wrapped names.
+//!
+//! A fuse is a bit that, once burned, stays burned until the wrapped name expires. Owner
+//! fuses can only be burned once the parent has given up control (`PARENT_CANNOT_CONTROL`),
+//! and that in turn requires the parent itself to be locked (`CANNOT_UNWRAP`), so a chain
+//! of guarantees always leads up to a name the registrar controls by expiry alone.
+
+/// The name cannot be unwrapped, so the wrapper keeps enforcing its fuses.
+pub const CANNOT_UNWRAP: u32 = 1;
+/// No further fuses can be burned.
+pub const CANNOT_BURN_FUSES: u32 = 1 << 1;
+/// The resolver of the name cannot be changed.
+pub const CANNOT_SET_RESOLVER: u32 = 1 << 2;
+/// No new subdomains can be created; existing ones stay under the parent's control unless
+/// they burned `PARENT_CANNOT_CONTROL`.
+pub const CANNOT_CREATE_SUBDOMAIN: u32 = 1 << 3;
+/// The parent can no longer replace the owner or fuses of the name. Burned by the parent.
+pub const PARENT_CANNOT_CONTROL: u32 = 1 << 16;
+
+/// Fuses the owner of a name may burn.
+pub const OWNER_CONTROLLED: u32 =
+    CANNOT_UNWRAP | CANNOT_BURN_FUSES | CANNOT_SET_RESOLVER | CANNOT_CREATE_SUBDOMAIN;
+/// Fuses only the parent of a name may burn.
+pub const PARENT_CONTROLLED: u32 = PARENT_CANNOT_CONTROL;
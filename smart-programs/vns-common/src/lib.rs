@@ -0,0 +1,135 @@
+#![no_std]
+
+//! Node, label and hashing primitives shared by the VNS programs.
+//!
+//! Everything here is `no_std` and independent of the programs themselves, so off-chain
+//! services can compute exactly the nodes the registry stores.
+
+extern crate alloc;
+
+pub mod coins;
+pub mod commitment;
+pub mod confusables;
+pub mod fuses;
+pub mod normalize;
+pub mod pagination;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sha3::{Digest, Keccak256};
+
+pub use gprimitives::U256;
+
+/// A node in the registry: the namehash of a dotted name.
+pub type Node = U256;
+/// A single label of a name, e.g. `alice` in `alice.vara`, as raw bytes.
+pub type Label = Vec<u8>;
+
+#[inline(always)]
+fn blake2<const N: usize>(data: &[u8]) -> [u8; N] {
+    blake2b_simd::Params::new()
+        .hash_length(N)
+        .hash(data)
+        .as_bytes()
+        .try_into()
+        .expect("slice is always the necessary length")
+}
+
+pub fn blake2_256(data: &[u8]) -> [u8; 32] {
+    blake2(data)
+}
+
+pub fn keccak_256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Node from its byte representation (little-endian, as hashed by `subnode`).
+pub fn node_from_bytes(bytes: &[u8; 32]) -> Node {
+    U256::from_little_endian(bytes)
+}
+
+/// Byte representation of a node (little-endian, as hashed by `subnode`).
+pub fn node_to_bytes(node: Node) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    node.to_little_endian(&mut bytes);
+    bytes
+}
+
+/// The root node, parent of every top-level domain.
+pub fn root_node() -> Node {
+    U256::zero()
+}
+
+/// Hash of a single label, as passed to the registry's `set_subnode_owner`.
+pub fn labelhash(label: &[u8]) -> U256 {
+    node_from_bytes(&blake2_256(label))
+}
+
+/// Node of `label` under `parent`: `blake2_256(parent || labelhash)`.
+pub fn subnode(parent: Node, labelhash: U256) -> Node {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(&node_to_bytes(parent));
+    preimage[32..].copy_from_slice(&node_to_bytes(labelhash));
+    node_from_bytes(&blake2_256(&preimage))
+}
+
+/// Node of a full dotted name such as `alice.vara`; the empty name is the root node.
+pub fn namehash(name: &str) -> Node {
+    HashScheme::Blake2.namehash(name)
+}
+
+/// Name of the subtree holding reverse records.
+pub const REVERSE_NAME: &str = "addr.reverse";
+
+/// Label of an account's node under `addr.reverse`: its 32 bytes in lowercase hex.
+pub fn reverse_label(account: &[u8; 32]) -> Label {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    account
+        .iter()
+        .flat_map(|byte| [HEX[(byte >> 4) as usize], HEX[(byte & 0x0f) as usize]])
+        .collect()
+}
+
+/// How the registry derives nodes, chosen once when the registry is initialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum HashScheme {
+    /// `blake2_256` over little-endian nodes (`labelhash`, `subnode`, `namehash`).
+    #[default]
+    Blake2,
+    /// EIP-137 namehash as used by ENS: `keccak256` over big-endian nodes.
+    Keccak,
+}
+
+impl HashScheme {
+    /// Hash of a single label under this scheme.
+    pub fn labelhash(self, label: &[u8]) -> U256 {
+        match self {
+            HashScheme::Blake2 => labelhash(label),
+            HashScheme::Keccak => U256::from_big_endian(&keccak_256(label)),
+        }
+    }
+
+    /// Node of a label under `parent` under this scheme.
+    pub fn subnode(self, parent: Node, labelhash: U256) -> Node {
+        match self {
+            HashScheme::Blake2 => subnode(parent, labelhash),
+            HashScheme::Keccak => {
+                let mut preimage = [0u8; 64];
+                parent.to_big_endian(&mut preimage[..32]);
+                labelhash.to_big_endian(&mut preimage[32..]);
+                U256::from_big_endian(&keccak_256(&preimage))
+            }
+        }
+    }
+
+    /// Node of a full dotted name under this scheme; the empty name is the root node.
+    pub fn namehash(self, name: &str) -> Node {
+        if name.is_empty() {
+            return root_node();
+        }
+        name.rsplit('.').fold(root_node(), |parent, label| {
+            self.subnode(parent, self.labelhash(label.as_bytes()))
+        })
+    }
+}
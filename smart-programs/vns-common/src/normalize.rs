@@ -0,0 +1,82 @@
+//! Label normalization, in the spirit of UTS-46.
+//!
+//! Labels are lowercased and put in NFC, then rejected if they contain a dot, a code point
+//! that cannot appear in a name, or fewer than `MIN_LABEL_CHARS` characters. Two inputs
+//! that normalize to the same label are the same name.
+
+use alloc::{string::String, vec::Vec};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::Label;
+
+/// Fewest characters a normalized label may have.
+pub const MIN_LABEL_CHARS: usize = 3;
+
+/// Why a label was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum LabelError {
+    /// The label is not valid UTF-8.
+    InvalidUtf8,
+    /// The label has fewer than `MIN_LABEL_CHARS` characters.
+    TooShort,
+    /// The label contains a dot, or a character normalizing to one.
+    ContainsDot,
+    /// The label contains a code point that cannot appear in a name.
+    DisallowedCodePoint(u32),
+}
+
+/// Dots and the characters UTS-46 maps to a dot.
+fn is_dot(c: char) -> bool {
+    matches!(c, '.' | '\u{3002}' | '\u{FF0E}' | '\u{FF61}')
+}
+
+/// ASCII is limited to letters, digits, `-` and `_`. Beyond ASCII, whitespace, control,
+/// invisible formatting, private-use and replacement characters are disallowed.
+fn is_disallowed(c: char) -> bool {
+    if c.is_ascii() {
+        return !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    }
+    c.is_whitespace()
+        || c.is_control()
+        || matches!(c,
+            '\u{00AD}'                      // soft hyphen
+            | '\u{200B}'..='\u{200F}'       // zero-width spaces and joiners, direction marks
+            | '\u{2028}'..='\u{202E}'       // separators and bidi overrides
+            | '\u{2060}'..='\u{206F}'       // invisible operators and bidi isolates
+            | '\u{FEFF}'                    // byte order mark
+            | '\u{E000}'..='\u{F8FF}'       // private use
+            | '\u{FFF0}'..='\u{FFFF}'       // specials, including U+FFFD
+        )
+}
+
+/// Normalize `label`: lowercase, NFC, then validate. Returns the label's normalized bytes.
+pub fn normalize_label(label: &[u8]) -> Result<Label, LabelError> {
+    let normalized = normalize(label)?;
+    if normalized.chars().count() < MIN_LABEL_CHARS {
+        return Err(LabelError::TooShort);
+    }
+    Ok(Vec::from(normalized))
+}
+
+/// Like `normalize_label`, without the minimum length: for labels of names that already
+/// exist, such as short subdomain labels, rather than of names being registered.
+pub fn normalize_any_length(label: &[u8]) -> Result<Label, LabelError> {
+    normalize(label).map(Vec::from)
+}
+
+fn normalize(label: &[u8]) -> Result<String, LabelError> {
+    let label = core::str::from_utf8(label).map_err(|_| LabelError::InvalidUtf8)?;
+    let normalized: String = label.chars().flat_map(char::to_lowercase).nfc().collect();
+
+    for c in normalized.chars() {
+        if is_dot(c) {
+            return Err(LabelError::ContainsDot);
+        }
+        if is_disallowed(c) {
+            return Err(LabelError::DisallowedCodePoint(c as u32));
+        }
+    }
+    Ok(normalized)
+}
@@ -0,0 +1,51 @@
+//! Cursor-based pages over ordered maps and sets.
+//!
+//! A cursor is the last key of the previous page, so pages stay stable while entries are
+//! inserted or removed elsewhere in the map.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::ops::Bound;
+
+/// Upper bound on the number of entries returned in one page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Up to `limit` entries (at most `MAX_PAGE_SIZE`) following `cursor`, or from the start
+/// without one, and the cursor of the next page if there are more entries.
+pub fn page<K: Ord + Clone, V: Clone>(
+    map: &BTreeMap<K, V>,
+    cursor: Option<&K>,
+    limit: u32,
+) -> (Vec<(K, V)>, Option<K>) {
+    let limit = limit.min(MAX_PAGE_SIZE) as usize;
+    let start = cursor.map_or(Bound::Unbounded, Bound::Excluded);
+    let mut entries = map.range((start, Bound::Unbounded));
+
+    let items: Vec<(K, V)> = entries
+        .by_ref()
+        .take(limit)
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    let next_cursor = match (items.last(), entries.next()) {
+        (Some((last, _)), Some(_)) => Some(last.clone()),
+        _ => None,
+    };
+    (items, next_cursor)
+}
+
+/// Up to `limit` keys (at most `MAX_PAGE_SIZE`) of `set` following `cursor`, or from the
+/// start without one, and the cursor of the next page if there are more keys.
+pub fn page_set<K: Ord + Clone>(set: &BTreeSet<K>, cursor: Option<&K>, limit: u32) -> (Vec<K>, Option<K>) {
+    let limit = limit.min(MAX_PAGE_SIZE) as usize;
+    let start = cursor.map_or(Bound::Unbounded, Bound::Excluded);
+    let mut keys = set.range((start, Bound::Unbounded));
+
+    let items: Vec<K> = keys.by_ref().take(limit).cloned().collect();
+    let next_cursor = match (items.last(), keys.next()) {
+        (Some(last), Some(_)) => Some(last.clone()),
+        _ => None,
+    };
+    (items, next_cursor)
+}
@@ -0,0 +1,42 @@
+use vns_common::coins::{is_valid_address, BTC, DOT, ETH, MAX_ADDRESS_LENGTH, VARA};
+
+#[test]
+fn bitcoin_addresses_are_standard_scripts() {
+    let mut p2pkh = vec![0x76, 0xa9, 0x14];
+    p2pkh.extend_from_slice(&[0x11; 20]);
+    p2pkh.extend_from_slice(&[0x88, 0xac]);
+    assert!(is_valid_address(BTC, &p2pkh));
+
+    let mut p2sh = vec![0xa9, 0x14];
+    p2sh.extend_from_slice(&[0x22; 20]);
+    p2sh.push(0x87);
+    assert!(is_valid_address(BTC, &p2sh));
+
+    let mut p2wpkh = vec![0x00, 0x14];
+    p2wpkh.extend_from_slice(&[0x33; 20]);
+    assert!(is_valid_address(BTC, &p2wpkh));
+
+    let mut p2tr = vec![0x51, 0x20];
+    p2tr.extend_from_slice(&[0x44; 32]);
+    assert!(is_valid_address(BTC, &p2tr));
+
+    // A raw hash is not a script, and lengths must match the script type.
+    assert!(!is_valid_address(BTC, &[0x11; 20]));
+    assert!(!is_valid_address(BTC, &p2wpkh[..21]));
+}
+
+#[test]
+fn fixed_length_addresses() {
+    assert!(is_valid_address(ETH, &[0xab; 20]));
+    assert!(!is_valid_address(ETH, &[0xab; 32]));
+    assert!(is_valid_address(DOT, &[0xcd; 32]));
+    assert!(!is_valid_address(DOT, &[0xcd; 20]));
+    assert!(is_valid_address(VARA, &[0xef; 32]));
+}
+
+#[test]
+fn unknown_coin_types_are_bounded() {
+    assert!(is_valid_address(9_999, &[1]));
+    assert!(!is_valid_address(9_999, &[]));
+    assert!(!is_valid_address(9_999, &[1; MAX_ADDRESS_LENGTH + 1]));
+}
@@ -0,0 +1,34 @@
+use vns_common::blake2_256;
+use vns_common::commitment::{make_commitment, COMMITMENT_VERSION};
+
+#[test]
+fn commitments_follow_the_documented_layout() {
+    let (owner, secret, salt, resolver) = ([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]);
+
+    let mut preimage = vec![COMMITMENT_VERSION, 5 << 2];
+    preimage.extend_from_slice(b"alice");
+    preimage.extend_from_slice(&owner);
+    preimage.extend_from_slice(&secret);
+    preimage.extend_from_slice(&salt);
+    preimage.extend_from_slice(&1_000u64.to_le_bytes());
+    preimage.push(1);
+    preimage.extend_from_slice(&resolver);
+    assert_eq!(make_commitment(b"alice", &owner, &secret, &salt, 1_000, Some(&resolver)), blake2_256(&preimage));
+
+    preimage.truncate(preimage.len() - 33);
+    preimage.push(0);
+    assert_eq!(make_commitment(b"alice", &owner, &secret, &salt, 1_000, None), blake2_256(&preimage));
+}
+
+#[test]
+fn every_field_is_committed_to() {
+    let (owner, secret, salt) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+    let commitment = make_commitment(b"alice", &owner, &secret, &salt, 1_000, None);
+
+    assert_ne!(commitment, make_commitment(b"alicf", &owner, &secret, &salt, 1_000, None));
+    assert_ne!(commitment, make_commitment(b"alice", &[9; 32], &secret, &salt, 1_000, None));
+    assert_ne!(commitment, make_commitment(b"alice", &owner, &[9; 32], &salt, 1_000, None));
+    assert_ne!(commitment, make_commitment(b"alice", &owner, &secret, &[9; 32], 1_000, None));
+    assert_ne!(commitment, make_commitment(b"alice", &owner, &secret, &salt, 2_000, None));
+    assert_ne!(commitment, make_commitment(b"alice", &owner, &secret, &salt, 1_000, Some(&[0; 32])));
+}
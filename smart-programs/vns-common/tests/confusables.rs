@@ -0,0 +1,17 @@
+use vns_common::confusables::{is_confusable, skeleton};
+
+#[test]
+fn lookalikes_share_a_skeleton() {
+    assert!(is_confusable(b"paypal", "pаypal".as_bytes()));
+    assert!(is_confusable(b"google", b"g00gle"));
+    assert!(is_confusable(b"alice", b"a1ice"));
+    assert!(is_confusable("vitalik".as_bytes(), "vіtalik".as_bytes()));
+    assert_eq!(skeleton("ԁоt".as_bytes()), b"dot".to_vec());
+}
+
+#[test]
+fn distinct_labels_are_not_confusable() {
+    assert!(!is_confusable(b"alice", b"alicia"));
+    assert!(!is_confusable(b"bob", b"rob"));
+    assert!(!is_confusable("日本語".as_bytes(), "中国語".as_bytes()));
+}
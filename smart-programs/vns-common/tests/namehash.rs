@@ -0,0 +1,56 @@
+use vns_common::{namehash, reverse_label, HashScheme, REVERSE_NAME, U256};
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s.trim_start_matches("0x"), 16).unwrap()
+}
+
+// Vectors from EIP-137 and the ENS documentation.
+#[test]
+fn keccak_namehash_matches_ens_vectors() {
+    let ens = HashScheme::Keccak;
+    assert_eq!(ens.namehash(""), U256::zero());
+    assert_eq!(
+        ens.namehash("eth"),
+        hex("0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+    );
+    assert_eq!(
+        ens.namehash("foo.eth"),
+        hex("0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+    );
+}
+
+#[test]
+fn keccak_labelhash_matches_ens_vectors() {
+    assert_eq!(
+        HashScheme::Keccak.labelhash(b"eth"),
+        hex("0x4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0")
+    );
+}
+
+#[test]
+fn namehash_is_subnode_of_parent() {
+    for scheme in [HashScheme::Blake2, HashScheme::Keccak] {
+        let parent = scheme.namehash("vara");
+        let label = scheme.labelhash(b"alice");
+        assert_eq!(scheme.namehash("alice.vara"), scheme.subnode(parent, label));
+    }
+    assert_eq!(namehash("alice.vara"), HashScheme::Blake2.namehash("alice.vara"));
+}
+
+#[test]
+fn reverse_label_is_lowercase_hex() {
+    let mut account = [0u8; 32];
+    account[0] = 0xab;
+    account[31] = 0x01;
+    let label = reverse_label(&account);
+    assert_eq!(label.len(), 64);
+    assert_eq!(&label[..2], b"ab");
+    assert_eq!(&label[62..], b"01");
+
+    let name = format!("{}.{REVERSE_NAME}", String::from_utf8(label.clone()).unwrap());
+    let scheme = HashScheme::Blake2;
+    assert_eq!(
+        namehash(&name),
+        scheme.subnode(namehash(REVERSE_NAME), scheme.labelhash(&label))
+    );
+}
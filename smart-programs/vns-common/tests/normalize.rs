@@ -0,0 +1,31 @@
+use vns_common::normalize::{normalize_any_length, normalize_label, LabelError};
+
+#[test]
+fn labels_are_lowercased_and_composed() {
+    assert_eq!(normalize_label(b"Alice").unwrap(), b"alice".to_vec());
+    assert_eq!(normalize_label("ÁLVARO".as_bytes()).unwrap(), "álvaro".as_bytes().to_vec());
+    // `e` followed by a combining acute accent composes to `é`.
+    assert_eq!(normalize_label("cafe\u{301}".as_bytes()).unwrap(), "café".as_bytes().to_vec());
+    assert_eq!(normalize_label(b"my-name_42").unwrap(), b"my-name_42".to_vec());
+    assert_eq!(normalize_label("日本語".as_bytes()).unwrap(), "日本語".as_bytes().to_vec());
+}
+
+#[test]
+fn invalid_labels_are_rejected() {
+    assert_eq!(normalize_label(&[0x61, 0xff, 0x62]), Err(LabelError::InvalidUtf8));
+    assert_eq!(normalize_label(b""), Err(LabelError::TooShort));
+    assert_eq!(normalize_label(b"ab"), Err(LabelError::TooShort));
+    assert_eq!(normalize_label(b"a.vara"), Err(LabelError::ContainsDot));
+    assert_eq!(normalize_label("a\u{FF0E}vara".as_bytes()), Err(LabelError::ContainsDot));
+    assert_eq!(normalize_label(b"al ice"), Err(LabelError::DisallowedCodePoint(' ' as u32)));
+    assert_eq!(normalize_label(b"ali\nce"), Err(LabelError::DisallowedCodePoint('\n' as u32)));
+    assert_eq!(normalize_label("al\u{200B}ice".as_bytes()), Err(LabelError::DisallowedCodePoint(0x200B)));
+    assert_eq!(normalize_label(b"a/lice"), Err(LabelError::DisallowedCodePoint('/' as u32)));
+}
+
+#[test]
+fn existing_labels_may_be_short() {
+    assert_eq!(normalize_any_length(b"X").unwrap(), b"x".to_vec());
+    assert_eq!(normalize_any_length(b""), Ok(Vec::new()));
+    assert_eq!(normalize_any_length(b"a.b"), Err(LabelError::ContainsDot));
+}
@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, BTreeSet};
+use vns_common::pagination::{page, page_set, MAX_PAGE_SIZE};
+
+#[test]
+fn pages_cover_the_map_in_order() {
+    let map: BTreeMap<u32, u32> = (0..10).map(|k| (k, k * 10)).collect();
+
+    let (first, cursor) = page(&map, None, 4);
+    assert_eq!(first, vec![(0, 0), (1, 10), (2, 20), (3, 30)]);
+    assert_eq!(cursor, Some(3));
+
+    let (second, cursor) = page(&map, cursor.as_ref(), 4);
+    assert_eq!(second.first(), Some(&(4, 40)));
+    let (last, cursor) = page(&map, cursor.as_ref(), 4);
+    assert_eq!(last, vec![(8, 80), (9, 90)]);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn cursors_are_stable_under_inserts_and_removals() {
+    let mut map: BTreeMap<u32, ()> = [2, 4, 6, 8].into_iter().map(|k| (k, ())).collect();
+    let (_, cursor) = page(&map, None, 2);
+    assert_eq!(cursor, Some(4));
+
+    map.remove(&4);
+    map.insert(1, ());
+    map.insert(5, ());
+    let (next, _) = page(&map, cursor.as_ref(), 10);
+    assert_eq!(next.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![5, 6, 8]);
+}
+
+#[test]
+fn page_size_is_capped() {
+    let map: BTreeMap<u32, ()> = (0..500).map(|k| (k, ())).collect();
+    let (items, cursor) = page(&map, None, u32::MAX);
+    assert_eq!(items.len(), MAX_PAGE_SIZE as usize);
+    assert!(cursor.is_some());
+    let (items, cursor) = page(&map, None, 0);
+    assert!(items.is_empty());
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn sets_are_paged_like_maps() {
+    let set: BTreeSet<u32> = (0..5).collect();
+    let (first, cursor) = page_set(&set, None, 3);
+    assert_eq!((first, cursor), (vec![0, 1, 2], Some(2)));
+    let (rest, cursor) = page_set(&set, Some(&2), 3);
+    assert_eq!((rest, cursor), (vec![3, 4], None));
+}